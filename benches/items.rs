@@ -0,0 +1,124 @@
+//! Benchmarks of the item index at 1M items.
+//!
+//! Run with `cargo bench --bench items`. Uses plain timing, so results are
+//! only meant for comparing changes on the same machine.
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use iced::{Point, Rectangle, Size};
+use prism::chart::items::{Entry, Items};
+
+const COUNT: usize = 1_000_000;
+
+/// A cheap deterministic generator, to keep the benchmark free of
+/// dependencies.
+struct Lcg(u64);
+
+impl Lcg {
+    fn next(&mut self) -> f32 {
+        self.0 = self
+            .0
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+
+        (self.0 >> 40) as f32 / (1u64 << 24) as f32
+    }
+}
+
+fn bench<T>(name: &str, iterations: u32, mut f: impl FnMut() -> T) {
+    let start = Instant::now();
+    for _ in 0..iterations {
+        black_box(f());
+    }
+    let elapsed = start.elapsed();
+
+    println!(
+        "{name:<32} {:>12.3?} per iteration ({iterations} iterations)",
+        elapsed / iterations
+    );
+}
+
+fn points(rng: &mut Lcg) -> Vec<Entry<usize>> {
+    (0..COUNT)
+        .map(|index| Entry::new(index, Point::new(rng.next() * 1000.0, rng.next() * 1000.0)))
+        .collect()
+}
+
+fn main() {
+    let mut rng = Lcg(42);
+    let entries = points(&mut rng);
+
+    bench("build 1M points", 3, || {
+        let mut items = Items::default();
+        items.add_series(0, &entries);
+        items
+    });
+
+    let mut items = Items::default();
+    items.add_series(0, &entries);
+
+    let queries: Vec<_> = (0..10_000)
+        .map(|_| Point::new(rng.next() * 1000.0, rng.next() * 1000.0))
+        .collect();
+    let mut query = queries.iter().cycle();
+
+    bench("small box query", 10_000, || {
+        let point = query.next().unwrap();
+        items.collision(Rectangle::new(*point, Size::new(2.0, 2.0)))
+    });
+
+    bench("wide x range query", 100, || {
+        let point = query.next().unwrap();
+        items.collision(Rectangle::new(
+            Point::new(point.x, 0.0),
+            Size::new(10.0, 1000.0),
+        ))
+    });
+
+    let duplicates: Vec<_> = (0..COUNT)
+        .map(|index| Entry::new(index, Point::new((index % 100) as f32, 0.0)))
+        .collect();
+    bench("build 1M duplicates", 3, || {
+        let mut items = Items::default();
+        items.add_series(1, &duplicates);
+        items
+    });
+
+    let mut items = Items::default();
+    let start = Instant::now();
+    let keys: Vec<_> = entries.iter().map(|entry| items.insert(0, entry)).collect();
+    println!(
+        "{:<32} {:>12.3?} per item",
+        "insert single item",
+        start.elapsed() / COUNT as u32
+    );
+
+    let keys: Vec<_> = keys.into_iter().step_by(10).collect();
+
+    let mut moved = Duration::ZERO;
+    for (index, key) in keys.iter().enumerate() {
+        let entry = Entry::new(
+            index * 10,
+            Point::new(rng.next() * 1000.0, rng.next() * 1000.0),
+        );
+
+        let start = Instant::now();
+        items.update(*key, &entry);
+        moved += start.elapsed();
+    }
+    println!(
+        "{:<32} {:>12.3?} per item",
+        "move single item",
+        moved / 100_000
+    );
+
+    let start = Instant::now();
+    for key in &keys {
+        items.remove(*key);
+    }
+    println!(
+        "{:<32} {:>12.3?} per item",
+        "remove single item",
+        start.elapsed() / 100_000
+    );
+}
@@ -1,12 +1,33 @@
+pub mod annotation;
 mod axis;
 mod cartesian;
-mod items;
+pub mod colormap;
+mod coordinates;
+pub mod drag;
+pub mod grid;
+pub mod items;
+mod link;
+pub mod pie;
+mod polar;
+pub mod raster;
+pub mod reference;
 pub mod series;
+pub mod style;
+pub mod svg;
+mod target;
 
+pub use annotation::Annotation;
 use axis::Axis;
 pub use axis::Labels;
 use axis::Tick;
+pub use coordinates::CoordinateSystem;
+pub use grid::ChartGrid;
 use items::Items;
+pub use link::Link;
+pub use pie::PieChart;
+pub use reference::Reference;
+pub use style::{Catalog, Palette, Style};
+pub use target::Target;
 
 use core::f32;
 
@@ -16,23 +37,28 @@ use iced::advanced::Renderer as _;
 use iced::advanced::graphics::geometry::Renderer as _;
 use iced::advanced::graphics::text::Paragraph;
 use iced::advanced::text::Paragraph as _;
-use iced::advanced::widget::{Tree, tree};
-use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::advanced::widget::{self, Operation, Tree, operation, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, clipboard, layout, mouse, renderer};
 use iced::mouse::ScrollDelta;
 use iced::widget::canvas::{self, Path, Stroke};
 use iced::widget::text::{LineHeight, Shaping, Wrapping};
 use iced::{Element, Length, Rectangle, Size, mouse::Cursor};
-use iced::{Font, Renderer, Vector, alignment, touch};
+use iced::{Font, Renderer, Vector, alignment, keyboard, touch, window};
 
-use std::marker::PhantomData;
+use std::cell::RefCell;
 use std::ops::RangeInclusive;
 
 type StateFn<'a, Message, Id> = Box<dyn Fn(&State<Id>) -> Message + 'a>;
+type ItemDragFn<'a, Message, Id> = Box<dyn Fn(Id, usize, Point) -> Message + 'a>;
+type SelectFn<'a, Message, Id> = Box<dyn Fn(Vec<(Id, usize)>) -> Message + 'a>;
+type ViewFn<'a, Message> =
+    Box<dyn Fn(Option<RangeInclusive<f32>>, Option<RangeInclusive<f32>>) -> Message + 'a>;
 
 pub struct Chart<'a, Message, Id, Theme = iced::Theme>
 where
     Message: Clone,
     Id: Clone,
+    Theme: Catalog,
 {
     width: Length,
     height: Length,
@@ -54,33 +80,65 @@ where
 
     x_offset: f32,
 
+    polar: bool,
+
+    link: Option<Link>,
+
     items: Items<Id, usize>,
 
     series: Vec<Box<dyn series::Series<Id> + 'a>>,
+    /// Position in the palette for each series, `None` for explicit colors.
+    palette_slots: Vec<Option<usize>>,
+    palette: Palette,
+    annotations: Vec<Annotation>,
+    references: Vec<Reference>,
     cache: canvas::Cache,
 
     on_move: Option<StateFn<'a, Message, Id>>,
     on_press: Option<StateFn<'a, Message, Id>>,
     on_release: Option<StateFn<'a, Message, Id>>,
     on_scroll: Option<StateFn<'a, Message, Id>>,
-    //on_right_press: Option<Message>,
-    //on_right_release: Option<Message>,
-    //on_middle_press: Option<Message>,
-    //on_middle_release: Option<Message>,
-    //on_enter: Option<Message>,
+    on_right_press: Option<StateFn<'a, Message, Id>>,
+    on_right_release: Option<StateFn<'a, Message, Id>>,
+    on_middle_press: Option<StateFn<'a, Message, Id>>,
+    on_middle_release: Option<StateFn<'a, Message, Id>>,
+    on_double_click: Option<StateFn<'a, Message, Id>>,
+    on_enter: Option<StateFn<'a, Message, Id>>,
+    on_exit: Option<StateFn<'a, Message, Id>>,
+    on_drag_start: Option<StateFn<'a, Message, Id>>,
+    on_drag: Option<StateFn<'a, Message, Id>>,
+    on_drag_end: Option<StateFn<'a, Message, Id>>,
+    on_item_drag: Option<ItemDragFn<'a, Message, Id>>,
+    on_select: Option<SelectFn<'a, Message, Id>>,
+    selection: Vec<(Id, usize)>,
+    selection_area: Option<SelectionArea>,
+    on_view_change: Option<ViewFn<'a, Message>>,
+    hover: Hover,
+    id: Option<widget::Id>,
+    copy: Option<CopyFormat>,
+    on_copy_image: Option<Box<dyn Fn(raster::Image) -> Message + 'a>>,
     //on_move: Option<Box<dyn Fn(Point) -> Message + 'a>>,
-    //on_exit: Option<Message>,
     //interaction: Option<mouse::Interaction>,
-    theme_: PhantomData<Theme>,
+    class: Theme::Class<'a>,
 }
 
 impl<'a, Message, Id, Theme> Chart<'a, Message, Id, Theme>
 where
     Message: Clone,
     Id: Clone,
+    Theme: Catalog,
 {
     const X_RANGE_DEFAULT: RangeInclusive<f32> = 0.0..=10.0;
     const Y_RANGE_DEFAULT: RangeInclusive<f32> = 0.0..=10.0;
+    const POLAR_LABEL_SPACE: f32 = 30.0;
+    /// Share of the visible range an arrow key pans by.
+    const KEY_PAN: f32 = 0.1;
+    /// Factor `+` and `-` zoom by.
+    const KEY_ZOOM: f32 = 1.25;
+    /// Distance in pixels the cursor has to move before a press becomes a drag.
+    const DRAG_THRESHOLD: f32 = 3.0;
+    /// Radius in pixels of the marker around the item stepped to with `Tab`.
+    const STEP_MARKER_RADIUS: f32 = 6.0;
 
     pub fn new() -> Self {
         Self {
@@ -104,18 +162,72 @@ where
 
             x_offset: 0.0,
 
+            polar: false,
+
+            link: None,
+
             items: Items::default(),
 
             series: Vec::new(),
+            palette_slots: Vec::new(),
+            palette: Palette::default(),
+            annotations: Vec::new(),
+            references: Vec::new(),
             cache: canvas::Cache::new(),
             on_move: None,
             on_press: None,
             on_release: None,
             on_scroll: None,
-            theme_: PhantomData,
+            on_right_press: None,
+            on_right_release: None,
+            on_middle_press: None,
+            on_middle_release: None,
+            on_double_click: None,
+            on_enter: None,
+            on_exit: None,
+            on_drag_start: None,
+            on_drag: None,
+            on_drag_end: None,
+            on_item_drag: None,
+            on_select: None,
+            selection: Vec::new(),
+            selection_area: None,
+            on_view_change: None,
+            hover: Hover::default(),
+            id: None,
+            copy: None,
+            on_copy_image: None,
+            class: Theme::default(),
         }
     }
 
+    /// How the items under the cursor are found, see [`State::items`].
+    pub fn hover(mut self, hover: Hover) -> Self {
+        self.hover = hover;
+        self
+    }
+
+    /// Publishes the ranges a keyboard pan or zoom of the focused chart moves
+    /// to, or `None` for both when `Home` goes back to the computed ranges.
+    ///
+    /// The app then owns the view and passes the ranges back with
+    /// [`Self::x_range`] and [`Self::y_range`]. Without it the chart keeps the
+    /// view itself, until the app changes either range.
+    pub fn on_view_change(
+        mut self,
+        msg: impl Fn(Option<RangeInclusive<f32>>, Option<RangeInclusive<f32>>) -> Message + 'a,
+    ) -> Self {
+        self.on_view_change = Some(Box::new(msg));
+        self
+    }
+
+    /// Sets the [`widget::Id`], e.g. to focus the chart with
+    /// [`operation::focusable::focus`].
+    pub fn id(mut self, id: impl Into<widget::Id>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// set width
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -164,6 +276,25 @@ where
         self
     }
 
+    /// Draws the chart in polar coordinates, with `x` as the angle and `y` as
+    /// the radius.
+    ///
+    /// The x range makes up one full turn, the x ticks become spokes and the y
+    /// ticks become rings. A computed x range is extended by one spoke, so that
+    /// the first and last value don't end up on the same spoke, e.g. with one
+    /// spoke for each category of a radar chart.
+    pub fn polar(mut self) -> Self {
+        self.polar = true;
+        self
+    }
+
+    /// Synchronizes the cursor and x range with every other chart using the
+    /// same [`Link`].
+    pub fn link(mut self, link: &Link) -> Self {
+        self.link = Some(link.clone());
+        self
+    }
+
     pub fn x_ticks(mut self, ticks: Tick) -> Self {
         self.x_ticks = ticks;
         self
@@ -184,10 +315,36 @@ where
         self
     }
 
+    /// Sets the style of the [`Chart`].
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as style::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`Chart`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    /// Colors for series without an explicit color, assigned in the order the
+    /// series are pushed.
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
     pub fn push_series(mut self, series: impl series::Series<Id> + 'a) -> Self {
         if let Some((id, items)) = series.items() {
             self.items.add_series(id, &items);
         }
+        let slot = series
+            .uses_palette()
+            .then(|| self.palette_slots.iter().flatten().count());
+        self.palette_slots.push(slot);
         self.series.push(Box::new(series));
 
         self
@@ -200,6 +357,41 @@ where
         series_list.into_iter().fold(self, Self::push_series)
     }
 
+    pub fn push_annotation(mut self, annotation: Annotation) -> Self {
+        self.annotations.push(annotation);
+        self
+    }
+
+    pub fn extend_annotations(self, annotations: impl IntoIterator<Item = Annotation>) -> Self {
+        annotations.into_iter().fold(self, Self::push_annotation)
+    }
+
+    pub fn push_reference(mut self, reference: Reference) -> Self {
+        self.references.push(reference);
+        self
+    }
+
+    /// Adds a horizontal line at `y`, use [`Chart::push_reference`] for labels
+    /// and styling.
+    pub fn hline(self, y: f32) -> Self {
+        self.push_reference(Reference::hline(y))
+    }
+
+    /// Adds a vertical line at `x`.
+    pub fn vline(self, x: f32) -> Self {
+        self.push_reference(Reference::vline(x))
+    }
+
+    /// Shades the area between two y values.
+    pub fn hspan(self, y: RangeInclusive<f32>) -> Self {
+        self.push_reference(Reference::hspan(y))
+    }
+
+    /// Shades the area between two x values.
+    pub fn vspan(self, x: RangeInclusive<f32>) -> Self {
+        self.push_reference(Reference::vspan(x))
+    }
+
     pub fn on_press(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
         self.on_press = Some(Box::new(msg));
         self
@@ -220,7 +412,118 @@ where
         self
     }
 
-    fn draw_x_axis(&self, frame: &mut canvas::Frame, plane: &Plane) {
+    pub fn on_right_press(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_right_press = Some(Box::new(msg));
+        self
+    }
+
+    pub fn on_right_release(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_right_release = Some(Box::new(msg));
+        self
+    }
+
+    pub fn on_middle_press(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_middle_press = Some(Box::new(msg));
+        self
+    }
+
+    pub fn on_middle_release(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_middle_release = Some(Box::new(msg));
+        self
+    }
+
+    /// Published on the second press of a double click, before
+    /// [`Self::on_press`].
+    pub fn on_double_click(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_double_click = Some(Box::new(msg));
+        self
+    }
+
+    /// Published when the cursor enters the plot area.
+    pub fn on_enter(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_enter = Some(Box::new(msg));
+        self
+    }
+
+    /// Published when the cursor leaves the plot area or the window.
+    pub fn on_exit(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_exit = Some(Box::new(msg));
+        self
+    }
+
+    /// Published once the cursor moved a few pixels with the left button held
+    /// down, see [`State::drag_delta`].
+    pub fn on_drag_start(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_drag_start = Some(Box::new(msg));
+        self
+    }
+
+    /// Published on every move of a drag.
+    pub fn on_drag(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_drag = Some(Box::new(msg));
+        self
+    }
+
+    /// Published when the button of a drag is released.
+    pub fn on_drag_end(mut self, msg: impl Fn(&State<Id>) -> Message + 'a) -> Self {
+        self.on_drag_end = Some(Box::new(msg));
+        self
+    }
+
+    /// Published while an item of a [draggable](series::PointSeries::draggable)
+    /// series is dragged, with the series id, the item index and where the
+    /// item has been moved to in data space.
+    ///
+    /// The chart only tracks the grab, the application moves the item.
+    pub fn on_item_drag(mut self, msg: impl Fn(Id, usize, Point) -> Message + 'a) -> Self {
+        self.on_item_drag = Some(Box::new(msg));
+        self
+    }
+
+    /// Published with the new selection when a click or a
+    /// [selection area](Self::selection_area) changes it.
+    ///
+    /// A click selects the item under the cursor, with Shift held it is added
+    /// to the selection and with Ctrl (Cmd on macOS) its selection is toggled.
+    /// The app owns the selection and passes it back with [`Self::selection`].
+    pub fn on_select(mut self, msg: impl Fn(Vec<(Id, usize)>) -> Message + 'a) -> Self {
+        self.on_select = Some(Box::new(msg));
+        self
+    }
+
+    /// The selected items, as published by [`Self::on_select`], which clicks
+    /// with Shift or Ctrl held extend.
+    pub fn selection(mut self, items: impl IntoIterator<Item = (Id, usize)>) -> Self {
+        self.selection = items.into_iter().collect();
+        self
+    }
+
+    /// Lets a drag select every item within a rectangle or a freehand lasso,
+    /// with the same modifiers as a click.
+    pub fn selection_area(mut self, area: SelectionArea) -> Self {
+        self.selection_area = Some(area);
+        self
+    }
+
+    /// Copies the chart to the clipboard on Ctrl+C (Cmd+C on macOS), with or
+    /// without Shift, while the cursor is over it.
+    pub fn copy(mut self, format: CopyFormat) -> Self {
+        self.copy = Some(format);
+        self
+    }
+
+    /// Renders the chart into an image on Ctrl+C (Cmd+C on macOS) while the
+    /// cursor is over it.
+    ///
+    /// The clipboard of iced only holds text, so the image is handed to the
+    /// application instead. It is rendered at the scale factor of the window,
+    /// once a window event reported it.
+    pub fn on_copy_image(mut self, msg: impl Fn(raster::Image) -> Message + 'a) -> Self {
+        self.on_copy_image = Some(Box::new(msg));
+        self
+    }
+
+    fn draw_x_axis(&self, frame: &mut dyn Target, plane: &Plane, style: &Style) {
         let bounds = frame.size();
 
         let mut scaled_bottom_left = plane.scale_to_cartesian(plane.bottom_left());
@@ -237,7 +540,7 @@ where
             &Path::line(scaled_bottom_left, scaled_bottom_right),
             Stroke::default()
                 .with_width(self.x_axis.width)
-                .with_color(self.x_axis.color),
+                .with_color(self.x_axis.color.unwrap_or(style.axis)),
         );
 
         // ticks
@@ -260,9 +563,13 @@ where
                 &Path::line(x_start, x_end),
                 Stroke::default()
                     .with_width(self.x_ticks.width)
-                    .with_color(self.x_ticks.color),
+                    .with_color(self.x_ticks.color.unwrap_or(style.tick)),
             );
 
+            if self.x_labels.hidden {
+                return;
+            }
+
             let label = self
                 .x_labels
                 .format
@@ -276,8 +583,7 @@ where
                     // TODO remove magic number,
                     y: y_scaled + 8.0,
                 },
-                // TODO use theme
-                color: self.x_labels.color.unwrap_or(iced::Color::WHITE),
+                color: self.x_labels.color.unwrap_or(style.label),
                 // TODO edge case center tick
                 align_x: alignment::Horizontal::Center,
                 align_y: alignment::Vertical::Top,
@@ -297,7 +603,7 @@ where
         }
     }
 
-    fn draw_y_axis(&self, frame: &mut canvas::Frame, plane: &Plane) {
+    fn draw_y_axis(&self, frame: &mut dyn Target, plane: &Plane, style: &Style) {
         let text_width = |text: &str, font_size| {
             let text = iced::advanced::text::Text {
                 content: text,
@@ -325,7 +631,11 @@ where
                 .format
                 .map_or_else(|| format!("{y}"), |fmt| fmt(&y));
             let font_size = self.y_labels.font_size.unwrap_or(12.into());
-            let label_width = text_width(&label, font_size);
+            let label_width = if self.y_labels.hidden {
+                0.0
+            } else {
+                text_width(&label, font_size)
+            };
             max_label_width = max_label_width.max(label_width);
         }
 
@@ -337,7 +647,11 @@ where
                 .format
                 .map_or_else(|| format!("{y}"), |fmt| fmt(&y));
             let font_size = self.y_labels.font_size.unwrap_or(12.into());
-            let label_width = text_width(&label, font_size);
+            let label_width = if self.y_labels.hidden {
+                0.0
+            } else {
+                text_width(&label, font_size)
+            };
             max_label_width = max_label_width.max(label_width);
         }
         let bounds = frame.size();
@@ -358,7 +672,7 @@ where
             &Path::line(scaled_bottom_center, scaled_top_center),
             Stroke::default()
                 .with_width(self.y_axis.width)
-                .with_color(self.y_axis.color),
+                .with_color(self.y_axis.color.unwrap_or(style.axis)),
         );
 
         let mut draw_y_tick = |y| {
@@ -379,9 +693,13 @@ where
                 &Path::line(start, end), //.transform(&Transform2D::new(1.0, 0.0, 0.0, -1.0, 0.0, 0.0)),
                 Stroke::default()
                     .with_width(self.y_ticks.width)
-                    .with_color(self.y_ticks.color),
+                    .with_color(self.y_ticks.color.unwrap_or(style.tick)),
             );
 
+            if self.y_labels.hidden {
+                return;
+            }
+
             let label = self
                 .y_labels
                 .format
@@ -396,8 +714,7 @@ where
                     x: x_scaled - 8.0,
                     y: y_scaled,
                 },
-                // TODO use theme
-                color: self.y_labels.color.unwrap_or(iced::Color::WHITE),
+                color: self.y_labels.color.unwrap_or(style.label),
                 // TODO edge case center tick
                 align_x: alignment::Horizontal::Right,
                 align_y: alignment::Vertical::Center,
@@ -416,96 +733,316 @@ where
         }
     }
 
-    fn draw_data(&self, frame: &mut canvas::Frame, plane: &Plane) {
-        for series in &self.series {
-            series.draw(frame, plane);
+    fn draw_data(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, style: &Style) {
+        self.draw_annotations(frame, coordinates, style, annotation::Layer::Below);
+
+        let colors = self.palette.colors(style);
+
+        for (series, slot) in self.series.iter().zip(&self.palette_slots) {
+            let color = match slot {
+                Some(slot) if !colors.is_empty() => colors[slot % colors.len()],
+                _ => style.axis,
+            };
+
+            series.draw(frame, coordinates, color);
+            series.draw_legend(frame, coordinates, style);
         }
+
+        self.draw_annotations(frame, coordinates, style, annotation::Layer::Above);
     }
 
-    fn compute_x_range_from_series(&self) -> RangeInclusive<f32> {
-        let mut max: Option<RangeInclusive<f32>> = None;
+    fn draw_annotations(
+        &self,
+        frame: &mut dyn Target,
+        coordinates: &dyn CoordinateSystem,
+        style: &Style,
+        layer: annotation::Layer,
+    ) {
+        self.references
+            .iter()
+            .filter(|reference| reference.layer == layer)
+            .for_each(|reference| reference.draw(frame, coordinates, style.axis));
+
+        self.annotations
+            .iter()
+            .filter(|annotation| annotation.layer == layer)
+            .for_each(|annotation| annotation.draw(frame, coordinates, style.label));
+    }
 
-        for series in &self.series {
-            let cur = series.x_range();
+    fn draw_polar_axes(&self, frame: &mut dyn Target, plane: &polar::Plane, style: &Style) {
+        let center = plane.center;
 
-            max = match max {
-                Some(max) => {
-                    let min = max.start().min(*cur.start());
-                    let max = max.end().max(*cur.end());
+        // rings
+        let rings = self.y_ticks.amount.max(1);
+        for i in 1..=rings {
+            let value = plane.distance.min + i as f32 * plane.distance.length / rings as f32;
+            let radius = plane.scale_to_radius(value);
 
-                    Some(min..=max)
-                }
-                None => Some(cur),
-            }
+            let (color, width) = if i == rings {
+                (self.y_axis.color.unwrap_or(style.axis), self.y_axis.width)
+            } else {
+                (self.y_ticks.color.unwrap_or(style.grid), self.y_ticks.width)
+            };
+
+            frame.stroke(
+                &Path::circle(center, radius),
+                Stroke::default().with_width(width).with_color(color),
+            );
+
+            let label = self
+                .y_labels
+                .format
+                .map_or_else(|| format!("{value}"), |fmt| fmt(&value));
+
+            frame.fill_text(canvas::Text {
+                content: label,
+                size: self.y_labels.font_size.unwrap_or(12.into()),
+                position: Point {
+                    // TODO remove magic number,
+                    x: center.x + 4.0,
+                    y: center.y - radius,
+                },
+                color: self.y_labels.color.unwrap_or(style.label),
+                align_x: alignment::Horizontal::Left,
+                align_y: alignment::Vertical::Bottom,
+                font: Font::MONOSPACE,
+                ..canvas::Text::default()
+            });
         }
 
-        max.unwrap_or(Self::X_RANGE_DEFAULT)
+        // spokes
+        let spokes = self.x_ticks.amount.max(1);
+        for i in 0..spokes {
+            let value = plane.angle.min + i as f32 * plane.angle.length / spokes as f32;
+            let angle = plane.scale_to_angle(value);
+            let direction = Vector::new(angle.cos(), angle.sin());
+
+            frame.stroke(
+                &Path::line(center, center + direction * plane.radius),
+                Stroke::default()
+                    .with_width(self.x_ticks.width)
+                    .with_color(self.x_ticks.color.unwrap_or(style.grid)),
+            );
+
+            let label = self
+                .x_labels
+                .format
+                .map_or_else(|| format!("{value}"), |fmt| fmt(&value));
+
+            let align_x = if direction.x.abs() < 0.1 {
+                alignment::Horizontal::Center
+            } else if direction.x < 0.0 {
+                alignment::Horizontal::Right
+            } else {
+                alignment::Horizontal::Left
+            };
+
+            frame.fill_text(canvas::Text {
+                content: label,
+                size: self.x_labels.font_size.unwrap_or(12.into()),
+                // TODO remove magic number,
+                position: center + direction * (plane.radius + 8.0),
+                color: self.x_labels.color.unwrap_or(style.label),
+                align_x,
+                align_y: alignment::Vertical::Center,
+                font: Font::MONOSPACE,
+                ..canvas::Text::default()
+            });
+        }
     }
 
-    fn compute_y_range_from_series(&self) -> RangeInclusive<f32> {
-        let mut max: Option<RangeInclusive<f32>> = None;
+    /// Renders the chart into an SVG document of the given size, without a
+    /// window or GPU.
+    pub fn to_svg(&self, size: Size, theme: &Theme) -> String {
+        let mut document = svg::Document::new(size);
+        self.export(&mut document, &theme.style(&self.class), View::default());
 
-        for series in &self.series {
-            let cur = series.y_range();
+        document.finish()
+    }
 
-            max = match max {
-                Some(max) => {
-                    let min = max.start().min(*cur.start());
-                    let max = max.end().max(*cur.end());
+    /// Renders the chart on the CPU into an image of the given logical size,
+    /// with `scale_factor` physical pixels per logical pixel.
+    pub fn to_image(
+        &self,
+        size: Size,
+        scale_factor: f32,
+        theme: &Theme,
+    ) -> Result<raster::Image, raster::InvalidSize> {
+        let mut image = raster::Image::new(size, scale_factor)?;
+        self.export(&mut image, &theme.style(&self.class), View::default());
+
+        Ok(image)
+    }
 
-                    Some(min..=max)
-                }
-                None => Some(cur),
+    /// Draws the whole chart onto a target outside of the widget tree.
+    ///
+    /// Only solid backgrounds are exported. The `view` of a keyboard pan or zoom
+    /// is kept, so copies match what is on screen.
+    fn export(&self, target: &mut dyn Target, style: &Style, view: View) {
+        let size = target.size();
+
+        let mut state = State {
+            x_view: view.x,
+            y_view: view.y,
+            view_base: (self.x_range.clone(), self.y_range.clone()),
+            ..State::default()
+        };
+        self.update_planes(&mut state, size);
+
+        let mut fill = |bounds: Rectangle, background, border: iced::Border| {
+            let path = Path::rounded_rectangle(bounds.position(), bounds.size(), border.radius);
+
+            if let Some(iced::Background::Color(color)) = background {
+                target.fill(&path, color.into());
             }
+            if border.width > 0.0 {
+                target.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_width(border.width)
+                        .with_color(border.color),
+                );
+            }
+        };
+
+        fill(Rectangle::with_size(size), style.background, style.border);
+        if let Some(plane) = &state.plane {
+            fill(plane.area(), style.plot_background, style.plot_border);
+        } else if let Some(plane) = &state.polar {
+            let radius = Vector::new(plane.radius, plane.radius);
+            let area = Rectangle::new(plane.center - radius, Size::new(radius.x, radius.y) * 2.0);
+
+            fill(
+                area,
+                style.plot_background,
+                style.plot_border.rounded(plane.radius),
+            );
         }
 
-        max.unwrap_or(Self::Y_RANGE_DEFAULT)
+        self.draw_on(target, &state, style);
     }
-}
 
-impl<Message, Id, Theme> Widget<Message, Theme, Renderer> for Chart<'_, Message, Id, Theme>
-where
-    Message: Clone,
-    Id: 'static + Clone + PartialEq,
-{
-    fn size(&self) -> Size<Length> {
-        Size::new(self.width, self.height)
+    /// The visible data of all series as tab separated values, one block per
+    /// series.
+    fn to_tsv(&self, coordinates: &dyn CoordinateSystem) -> String {
+        self.series
+            .iter()
+            .filter_map(|series| series.table(coordinates))
+            .map(|table| table.to_tsv())
+            .collect::<Vec<_>>()
+            .join("\n\n")
     }
 
-    fn tag(&self) -> tree::Tag {
-        tree::Tag::of::<State<Id>>()
+    fn copy_to(
+        &self,
+        clipboard: &mut dyn Clipboard,
+        state: &State<Id>,
+        size: Size,
+    ) -> Option<Message> {
+        let style = state.style.borrow().clone()?;
+
+        match self.copy {
+            Some(CopyFormat::Data) => {
+                if let Some(coordinates) = state.coordinates() {
+                    clipboard.write(clipboard::Kind::Standard, self.to_tsv(coordinates));
+                }
+            }
+            Some(CopyFormat::Svg) => {
+                let mut document = svg::Document::new(size);
+                self.export(&mut document, &style, state.view());
+
+                clipboard.write(clipboard::Kind::Standard, document.finish());
+            }
+            None => {}
+        }
+
+        let on_copy = self.on_copy_image.as_ref()?;
+        let mut image = raster::Image::new(size, state.scale_factor).ok()?;
+        self.export(&mut image, &style, state.view());
+
+        Some(on_copy(image))
     }
 
-    fn state(&self) -> tree::State {
-        tree::State::new(State::<Id>::default())
+    /// Whether a copy shortcut is set up, see [`Self::copy`].
+    fn copies(&self) -> bool {
+        self.copy.is_some() || self.on_copy_image.is_some()
     }
 
-    fn children(&self) -> Vec<Tree> {
-        vec![]
+    fn draw_on(&self, target: &mut dyn Target, state: &State<Id>, style: &Style) {
+        if let Some(plane) = &state.polar {
+            self.draw_polar_axes(target, plane, style);
+            self.draw_data(target, plane, style);
+        } else if let Some(plane) = &state.plane {
+            self.draw_data(target, plane, style);
+            self.draw_x_axis(target, plane, style);
+            self.draw_y_axis(target, plane, style);
+        }
     }
 
-    #[inline]
-    fn layout(
-        &self,
-        tree: &mut Tree,
-        _renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let node = layout::atomic(limits, self.width, self.height);
-        //limits.resolve(self.width, self.height, Size::ZERO);
+    /// Fills the chart and plot area, below everything drawn on the frame.
+    fn draw_background(
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        state: &State<Id>,
+        style: &Style,
+    ) {
+        let mut fill = |bounds, background: Option<iced::Background>, border: iced::Border| {
+            if background.is_none() && border.width == 0.0 {
+                return;
+            }
 
-        let x_range = match &self.x_range {
-            Some(range) => range,
-            None => &self.compute_x_range_from_series(),
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border,
+                    ..renderer::Quad::default()
+                },
+                background.unwrap_or(iced::Color::TRANSPARENT.into()),
+            );
         };
 
-        let y_range = match &self.y_range {
-            Some(range) => range,
-            None => &self.compute_y_range_from_series(),
+        fill(bounds, style.background, style.border);
+
+        let offset = Vector::new(bounds.x, bounds.y);
+        if let Some(plane) = &state.plane {
+            fill(
+                plane.area() + offset,
+                style.plot_background,
+                style.plot_border,
+            );
+        } else if let Some(plane) = &state.polar {
+            let size = Size::new(plane.radius * 2.0, plane.radius * 2.0);
+            let area = Rectangle::new(plane.center - Vector::new(plane.radius, plane.radius), size);
+
+            fill(
+                area + offset,
+                style.plot_background,
+                style.plot_border.rounded(plane.radius),
+            );
+        }
+    }
+
+    /// Computes the planes mapping data onto a frame of the given size.
+    fn update_planes(&self, state: &mut State<Id>, size: Size) {
+        if state.view_base.0 != self.x_range || state.view_base.1 != self.y_range {
+            state.x_view = None;
+            state.y_view = None;
+        }
+
+        let linked_range = self.link.as_ref().and_then(Link::x_range);
+        let x_range = match (
+            linked_range.as_ref().or(state.x_view.as_ref()),
+            &self.x_range,
+        ) {
+            (Some(range), _) | (None, Some(range)) => range,
+            (None, None) if self.polar => &self.compute_polar_x_range(),
+            (None, None) => &self.compute_x_range_from_series(),
         };
 
-        //let node = layout::Node::new(size);
-        let bounds = node.bounds();
+        let y_range = match (&state.y_view, &self.y_range) {
+            (Some(range), _) | (None, Some(range)) => range,
+            (None, None) => &self.compute_y_range_from_series(),
+        };
 
         let x_margin_min = self.margin.left;
         let x_margin_max = self.margin.right;
@@ -514,47 +1051,913 @@ where
 
         // let x_range = &(x_range.start() + self.x_offset..=x_range.end() + self.x_offset);
 
+        if self.polar {
+            let width = size.width - x_margin_min - x_margin_max;
+            let height = size.height - y_margin_min - y_margin_max;
+            let center = Point::new(x_margin_min + width / 2.0, y_margin_max + height / 2.0);
+            // leave room for the spoke labels
+            let radius = (width.min(height) / 2.0 - Self::POLAR_LABEL_SPACE).max(0.0);
+
+            let distance = match &self.y_range {
+                Some(range) => range.clone(),
+                None => y_range.start().min(0.0)..=*y_range.end(),
+            };
+
+            state.plane = None;
+            state.polar = Some(polar::Plane::new(x_range, &distance, center, radius));
+
+            return;
+        }
+
         let plane = Plane {
-            x: cartesian::Axis::new(x_range, x_margin_min, x_margin_max, bounds.width),
-            y: cartesian::Axis::new(y_range, y_margin_min, y_margin_max, bounds.height),
+            x: cartesian::Axis::new(x_range, x_margin_min, x_margin_max, size.width),
+            y: cartesian::Axis::new(y_range, y_margin_min, y_margin_max, size.height),
         };
 
-        let state = tree.state.downcast_mut::<State<Id>>();
         state.plane = Some(plane);
-
-        node
+        state.polar = None;
     }
 
-    #[inline]
-    fn draw(
-        &self,
-        tree: &Tree,
-        renderer: &mut Renderer,
-        _theme: &Theme,
-        _defaults: &renderer::Style,
-        layout: Layout<'_>,
-        _cursor: Cursor,
-        _viewport: &Rectangle,
+    fn update_link(
+        link: &Link,
+        state: &mut State<Id>,
+        event: &iced::Event,
+        bounds: Rectangle,
+        cursor: mouse::Cursor,
+        shell: &mut Shell<'_, Message>,
     ) {
-        let bounds = layout.bounds();
-        if bounds.width < 1.0 || bounds.height < 1.0 {
-            return;
-        }
-
-        let state: &State<Id> = tree.state.downcast_ref();
         let Some(plane) = &state.plane else {
             return;
         };
 
-        let geometry = self.cache.draw(renderer, bounds.size(), |frame| {
-            self.draw_data(frame, plane);
-            self.draw_x_axis(frame, plane);
-            self.draw_y_axis(frame, plane);
-        });
+        let position = cursor.position_in(bounds);
 
-        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
-            renderer.draw_geometry(geometry)
-        });
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(position) = position {
+                    state.pan_origin = Some((position, plane.x.min..=plane.x.max));
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. }) => {
+                state.pan_origin = None;
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | iced::Event::Touch(touch::Event::FingerMoved { .. })
+            | iced::Event::Mouse(mouse::Event::CursorLeft) => {
+                if let (Some((origin, range)), Some(position)) =
+                    (&state.pan_origin, cursor.position())
+                {
+                    let relative = position - Vector::new(bounds.x, bounds.y);
+                    let shift = (origin.x - relative.x) / plane.x.scale;
+
+                    link.set_x_range(Some(range.start() + shift..=range.end() + shift));
+                    shell.invalidate_layout();
+                    shell.request_redraw();
+                }
+
+                let cursor_x = position.map(|p| plane.get_cartesian(p).x);
+                if cursor_x.is_some() || state.link_hovered {
+                    state.link_hovered = cursor_x.is_some();
+
+                    if link.set_cursor_x(cursor_x) {
+                        shell.request_redraw();
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handles other buttons than the left one, double clicks, drags and the
+    /// cursor entering and leaving the plot area.
+    fn update_pointer(
+        &self,
+        state: &mut State<Id>,
+        event: &iced::Event,
+        bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Id: PartialEq,
+    {
+        let Some(position) = state.cursor_position else {
+            return;
+        };
+        let inside = bounds.contains(position + Vector::new(bounds.x, bounds.y));
+
+        let publish = |message: &Option<StateFn<'a, Message, Id>>,
+                       state: &State<Id>,
+                       shell: &mut Shell<'_, Message>| {
+            if let Some(message) = message {
+                shell.publish(message(state));
+            }
+        };
+
+        match event {
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. })
+                if inside =>
+            {
+                let click = mouse::Click::new(position, mouse::Button::Left, state.last_click);
+                state.last_click = Some(click);
+                state.drag = Some(Drag {
+                    origin: position,
+                    started: false,
+                });
+                state.grab = self.grab(state, position);
+                if state.grab.is_some() || self.selects_area() {
+                    // the item moves or the area is selected instead of
+                    // panning the linked charts
+                    state.pan_origin = None;
+                }
+
+                if click.kind() == mouse::click::Kind::Double {
+                    publish(&self.on_double_click, state, shell);
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) if inside => {
+                publish(&self.on_right_press, state, shell);
+            }
+            iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Middle)) if inside => {
+                publish(&self.on_middle_press, state, shell);
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. }) => {
+                if state.drag.as_ref().is_some_and(|drag| drag.started) {
+                    publish(&self.on_drag_end, state, shell);
+                }
+                let clicked = state.drag.as_ref().is_some_and(|drag| !drag.started);
+
+                state.drag = None;
+                state.grab = None;
+
+                if let Some(area) = state.area.take() {
+                    self.select_area(state, &area, shell);
+                } else if clicked {
+                    self.select_at(state, position, shell);
+                }
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Right)) if inside => {
+                publish(&self.on_right_release, state, shell);
+            }
+            iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Middle)) if inside => {
+                publish(&self.on_middle_release, state, shell);
+            }
+            iced::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | iced::Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut state.drag {
+                    let start =
+                        !drag.started && drag.origin.distance(position) >= Self::DRAG_THRESHOLD;
+                    drag.started |= start;
+
+                    let dragging = drag.started;
+                    if start {
+                        publish(&self.on_drag_start, state, shell);
+                    }
+                    if dragging {
+                        publish(&self.on_drag, state, shell);
+                        self.drag_item(state, shell);
+                        self.drag_area(state, shell);
+                    }
+                }
+
+                let hovered = inside && state.is_in_plot(position);
+                self.update_hover(state, hovered, shell);
+            }
+            _ => {}
+        }
+    }
+
+    fn selects_area(&self) -> bool {
+        self.on_select.is_some() && self.selection_area.is_some()
+    }
+
+    /// Extends the selection area to the cursor.
+    fn drag_area(&self, state: &mut State<Id>, shell: &mut Shell<'_, Message>) {
+        let (Some(drag), Some(position)) = (&state.drag, state.cursor_position) else {
+            return;
+        };
+        if state.grab.is_some() || !self.selects_area() {
+            return;
+        }
+
+        let origin = drag.origin;
+        let area = state.area.get_or_insert_with(|| vec![origin]);
+
+        match self.selection_area {
+            Some(SelectionArea::Rectangle) => {
+                area.truncate(1);
+                area.extend([
+                    Point::new(position.x, origin.y),
+                    position,
+                    Point::new(origin.x, position.y),
+                ]);
+            }
+            Some(SelectionArea::Lasso) => area.push(position),
+            None => {}
+        }
+
+        shell.request_redraw();
+    }
+
+    /// Selects the item under the cursor after a click.
+    fn select_at(&self, state: &mut State<Id>, position: Point, shell: &mut Shell<'_, Message>)
+    where
+        Id: PartialEq,
+    {
+        if self.on_select.is_none() {
+            return;
+        }
+        let Some(coordinates) = state.coordinates() else {
+            return;
+        };
+
+        // the item hovered first, as found by the hover mode
+        let (items, _) = self.hovered_at(coordinates, position);
+        self.select(state, items.into_iter().take(1).collect(), shell);
+    }
+
+    /// Selects the items whose location lies within the polygon.
+    fn select_area(&self, state: &mut State<Id>, area: &[Point], shell: &mut Shell<'_, Message>)
+    where
+        Id: PartialEq,
+    {
+        let Some(coordinates) = state.coordinates() else {
+            return;
+        };
+
+        let corners: Vec<_> = area.iter().map(|p| coordinates.to_data(*p)).collect();
+        let (min, max) = corners.iter().fold(
+            (
+                Point::new(f32::INFINITY, f32::INFINITY),
+                Point::new(f32::NEG_INFINITY, f32::NEG_INFINITY),
+            ),
+            |(min, max), p| {
+                (
+                    Point::new(min.x.min(p.x), min.y.min(p.y)),
+                    Point::new(max.x.max(p.x), max.y.max(p.y)),
+                )
+            },
+        );
+        let rect = Rectangle::new(min, Size::new(max.x - min.x, max.y - min.y));
+
+        let items = self
+            .items
+            .locate(rect)
+            .into_iter()
+            .filter(|(bounds, _)| contains(area, coordinates.to_screen(bounds.center())))
+            .map(|(_, item)| item)
+            .collect();
+
+        self.select(state, items, shell);
+    }
+
+    /// Combines the items with the selection according to the held modifiers
+    /// and publishes the result if it differs.
+    fn select(&self, state: &State<Id>, items: Vec<(Id, usize)>, shell: &mut Shell<'_, Message>)
+    where
+        Id: PartialEq,
+    {
+        let Some(on_select) = &self.on_select else {
+            return;
+        };
+
+        let selection = combine(&self.selection, items, state.modifiers);
+        if selection != self.selection {
+            shell.publish(on_select(selection));
+        }
+    }
+
+    /// The draggable item under the cursor, if any.
+    fn grab(&self, state: &State<Id>, position: Point) -> Option<Grab<Id>>
+    where
+        Id: PartialEq,
+    {
+        self.on_item_drag.as_ref()?;
+        let coordinates = state.coordinates()?;
+
+        let mut candidates = vec![];
+        for series in &self.series {
+            let (Some(id), Some(constraints), Some(collision_box)) =
+                (series.id(), series.draggable(), series.collision_box())
+            else {
+                continue;
+            };
+
+            let rect = data_rect(coordinates, position, collision_box.size());
+            candidates.extend(
+                self.items
+                    .locate(rect)
+                    .into_iter()
+                    .filter(|(_, item)| item.0 == id)
+                    .map(|(bounds, item)| {
+                        let (dx, dy) = screen_distance(coordinates, bounds, position);
+
+                        (dx.hypot(dy), bounds.position(), item, constraints)
+                    }),
+            );
+        }
+
+        // the nearest item, as collision boxes of close items overlap
+        let (_, origin, item, constraints) =
+            candidates.into_iter().min_by(|a, b| a.0.total_cmp(&b.0))?;
+
+        Some(Grab {
+            origin,
+            cursor: coordinates.to_data(position),
+            constraints: constraints.clone(),
+            item,
+        })
+    }
+
+    fn drag_item(&self, state: &State<Id>, shell: &mut Shell<'_, Message>) {
+        let (Some(on_item_drag), Some(grab), Some(cursor)) =
+            (&self.on_item_drag, &state.grab, state.get_coords())
+        else {
+            return;
+        };
+
+        let point = grab.origin + (cursor - grab.cursor);
+        let point = grab.constraints.apply(grab.origin, point);
+
+        let (series_id, index) = grab.item.clone();
+        shell.publish(on_item_drag(series_id, index, point));
+    }
+
+    /// The items within the collision boxes of their series around the
+    /// position.
+    fn items_at(&self, coordinates: &dyn CoordinateSystem, position: Point) -> Vec<(Id, usize)>
+    where
+        Id: PartialEq,
+    {
+        let iter = self
+            .series
+            .iter()
+            .filter_map(|s| s.id().zip(s.collision_box()));
+
+        let mut item_list = vec![];
+        for (series_id, collision_box) in iter {
+            let rect = data_rect(coordinates, position, collision_box.size());
+            item_list.extend(
+                self.items
+                    .collision(rect)
+                    .into_iter()
+                    .filter(|i| i.0 == series_id),
+            );
+        }
+
+        item_list
+    }
+
+    /// The items under the cursor and the lines hit by it, found and sorted as
+    /// described by the [`Hover`] mode.
+    fn hovered_at(
+        &self,
+        coordinates: &dyn CoordinateSystem,
+        position: Point,
+    ) -> (Vec<(Id, usize)>, Vec<LineHit<Id>>)
+    where
+        Id: PartialEq,
+    {
+        let (items, x) = match self.hover {
+            Hover::Collision => (self.items_at(coordinates, position), None),
+            Hover::Nearest { radius } | Hover::NearestX { radius } => {
+                let x_only = matches!(self.hover, Hover::NearestX { .. });
+                let items = self.nearest_at(coordinates, position, radius, x_only);
+
+                // lines are hit at the x of the nearest items as well
+                let x = items
+                    .first()
+                    .filter(|_| x_only)
+                    .map(|(bounds, _)| bounds.center_x());
+
+                (items.into_iter().map(|(_, item)| item).collect(), x)
+            }
+        };
+
+        (items, self.line_hits_at(coordinates, position, x))
+    }
+
+    /// The items within `radius` pixels of the position with their bounds,
+    /// nearest first.
+    ///
+    /// With `x_only` just the horizontal distance counts and only the items at
+    /// the nearest x are returned, ordered by their vertical distance.
+    fn nearest_at(
+        &self,
+        coordinates: &dyn CoordinateSystem,
+        position: Point,
+        radius: f32,
+        x_only: bool,
+    ) -> Vec<(Rectangle, (Id, usize))> {
+        let mut rect = data_rect(coordinates, position, Size::new(radius, radius) * 2.0);
+        if x_only {
+            let bounds = coordinates.bounds();
+            rect.y = bounds.y;
+            rect.height = bounds.height;
+        }
+
+        let mut items: Vec<_> = self
+            .items
+            .locate(rect)
+            .into_iter()
+            .map(|(bounds, item)| {
+                let (dx, dy) = screen_distance(coordinates, bounds, position);
+
+                (dx, dy, (bounds, item))
+            })
+            .filter(|(dx, dy, _)| {
+                if x_only {
+                    *dx <= radius
+                } else {
+                    dx.hypot(*dy) <= radius
+                }
+            })
+            .collect();
+
+        if x_only {
+            let nearest = items
+                .iter()
+                .map(|(dx, ..)| *dx)
+                .fold(f32::INFINITY, f32::min);
+
+            // items of several series at the same x, up to rounding
+            items.retain(|(dx, ..)| *dx - nearest < 0.5);
+            items.sort_by(|a, b| a.1.total_cmp(&b.1));
+        } else {
+            items.sort_by(|a, b| a.0.hypot(a.1).total_cmp(&b.0.hypot(b.1)));
+        }
+
+        items.into_iter().map(|(.., item)| item).collect()
+    }
+
+    /// The nearest segment of each line around the position, within its
+    /// collision box or the radius of the [`Hover`] mode, nearest first unless
+    /// the mode is [`Hover::Collision`].
+    ///
+    /// With [`Hover::NearestX`] just the horizontal distance to `x`, or the
+    /// cursor without it, counts and the lines are ordered by the vertical
+    /// distance of their value at it.
+    fn line_hits_at(
+        &self,
+        coordinates: &dyn CoordinateSystem,
+        position: Point,
+        x: Option<f32>,
+    ) -> Vec<LineHit<Id>>
+    where
+        Id: PartialEq,
+    {
+        let cursor = coordinates.to_data(position);
+        let x = x.unwrap_or(cursor.x);
+        let target = coordinates.to_screen(Point::new(x, cursor.y));
+
+        let iter = self
+            .series
+            .iter()
+            .filter_map(|s| s.id().zip(s.collision_box()));
+
+        let mut hits = vec![];
+        for (series_id, collision_box) in iter {
+            let (radius, x_only) = match self.hover {
+                Hover::Collision => (collision_box.width.min(collision_box.height) / 2.0, false),
+                Hover::Nearest { radius } => (radius, false),
+                Hover::NearestX { radius } => (radius, true),
+            };
+
+            let rect = if x_only {
+                let bounds = coordinates.bounds();
+                let rect = data_rect(
+                    coordinates,
+                    Point::new(target.x, position.y),
+                    Size::new(radius, radius) * 2.0,
+                );
+
+                Rectangle::new(
+                    Point::new(rect.x, bounds.y),
+                    Size::new(rect.width, bounds.height),
+                )
+            } else if self.hover == Hover::Collision {
+                data_rect(coordinates, position, collision_box.size())
+            } else {
+                data_rect(coordinates, position, Size::new(radius, radius) * 2.0)
+            };
+
+            let nearest = self
+                .items
+                .segments(rect)
+                .into_iter()
+                .filter(|(_, item)| item.0 == series_id)
+                .filter_map(|([a, b], (_, segment))| {
+                    let (start, end) = (coordinates.to_screen(a), coordinates.to_screen(b));
+
+                    let (distance, value) = if x_only {
+                        let dx = (start.x.min(end.x) - target.x)
+                            .max(target.x - start.x.max(end.x))
+                            .max(0.0);
+
+                        (dx, value_at(a, b, x))
+                    } else {
+                        let line = end - start;
+                        let length = line.x * line.x + line.y * line.y;
+
+                        let t = if length > 0.0 {
+                            let offset = position - start;
+                            ((offset.x * line.x + offset.y * line.y) / length).clamp(0.0, 1.0)
+                        } else {
+                            0.0
+                        };
+                        let closest = start + line * t;
+
+                        let value = if a.x == b.x {
+                            coordinates.to_data(closest)
+                        } else {
+                            value_at(a, b, cursor.x)
+                        };
+
+                        (closest.distance(position), value)
+                    };
+
+                    (distance <= radius).then_some((distance, segment, value))
+                })
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+
+            let Some((distance, segment, value)) = nearest else {
+                continue;
+            };
+
+            // ordered by the vertical distance at the shared x in x-only mode
+            let distance = if x_only {
+                (coordinates.to_screen(value).y - position.y).abs()
+            } else {
+                distance
+            };
+
+            hits.push((
+                distance,
+                LineHit {
+                    series: series_id,
+                    segment,
+                    value,
+                },
+            ));
+        }
+
+        if self.hover != Hover::Collision {
+            hits.sort_by(|a, b| a.0.total_cmp(&b.0));
+        }
+
+        hits.into_iter().map(|(_, hit)| hit).collect()
+    }
+
+    /// Publishes [`Self::on_enter`] or [`Self::on_exit`] when the plot area
+    /// hover changes.
+    fn update_hover(&self, state: &mut State<Id>, hovered: bool, shell: &mut Shell<'_, Message>) {
+        if state.plot_hovered == hovered {
+            return;
+        }
+        state.plot_hovered = hovered;
+
+        let message = if hovered {
+            &self.on_enter
+        } else {
+            &self.on_exit
+        };
+        if let Some(message) = message {
+            shell.publish(message(state));
+        }
+    }
+
+    /// Handles a key press of the focused chart, returns whether it was used.
+    fn update_keyboard(
+        &self,
+        state: &mut State<Id>,
+        key: &keyboard::Key,
+        modifiers: keyboard::Modifiers,
+        shell: &mut Shell<'_, Message>,
+    ) -> bool
+    where
+        Id: PartialEq,
+    {
+        use keyboard::key::Named;
+
+        if let keyboard::Key::Named(Named::Tab) = key {
+            let items = self.items.ordered();
+            if items.is_empty() {
+                return false;
+            }
+
+            let current = state
+                .item_list
+                .as_ref()
+                .and_then(|list| list.first())
+                .and_then(|item| items.iter().position(|(_, i)| i == item));
+
+            let next = match (current, modifiers.shift()) {
+                (None, false) => Some(0),
+                (None, true) => Some(items.len() - 1),
+                (Some(i), false) => Some(i + 1).filter(|next| *next < items.len()),
+                (Some(i), true) => i.checked_sub(1),
+            };
+
+            let (item_list, stepped) = match next {
+                Some(next) => (Some(vec![items[next].1.clone()]), Some(items[next].0)),
+                None => (None, None),
+            };
+
+            state.item_list = item_list;
+            state.stepped = stepped;
+            state.line_hits = None;
+            if let Some(message) = self.on_move.as_ref() {
+                shell.publish(message(state));
+            }
+            shell.request_redraw();
+
+            // past the last item focus moves on, instead of being trapped
+            return next.is_some();
+        }
+
+        if let keyboard::Key::Named(Named::Home) = key {
+            if let Some(link) = &self.link {
+                link.set_x_range(None);
+            }
+            match &self.on_view_change {
+                Some(message) => shell.publish(message(None, None)),
+                None => {
+                    state.x_view = None;
+                    state.y_view = None;
+                }
+            }
+        } else {
+            let Some(plane) = &state.plane else {
+                return false;
+            };
+
+            let (x_length, y_length) = (plane.x.max - plane.x.min, plane.y.max - plane.y.min);
+            let (x_center, y_center) = (plane.x.min + x_length / 2.0, plane.y.min + y_length / 2.0);
+
+            // (center shift, new length) per axis
+            let ((x_shift, x_length), (y_shift, y_length)) = match key.as_ref() {
+                keyboard::Key::Named(Named::ArrowLeft) => {
+                    ((-x_length * Self::KEY_PAN, x_length), (0.0, y_length))
+                }
+                keyboard::Key::Named(Named::ArrowRight) => {
+                    ((x_length * Self::KEY_PAN, x_length), (0.0, y_length))
+                }
+                keyboard::Key::Named(Named::ArrowDown) => {
+                    ((0.0, x_length), (-y_length * Self::KEY_PAN, y_length))
+                }
+                keyboard::Key::Named(Named::ArrowUp) => {
+                    ((0.0, x_length), (y_length * Self::KEY_PAN, y_length))
+                }
+                keyboard::Key::Character("+" | "=") => (
+                    (0.0, x_length / Self::KEY_ZOOM),
+                    (0.0, y_length / Self::KEY_ZOOM),
+                ),
+                keyboard::Key::Character("-") => (
+                    (0.0, x_length * Self::KEY_ZOOM),
+                    (0.0, y_length * Self::KEY_ZOOM),
+                ),
+                _ => return false,
+            };
+
+            let x_min = x_center + x_shift - x_length / 2.0;
+            let y_min = y_center + y_shift - y_length / 2.0;
+
+            let x_view = x_min..=x_min + x_length;
+            let y_view = y_min..=y_min + y_length;
+
+            if let Some(link) = &self.link {
+                link.set_x_range(Some(x_view.clone()));
+            }
+            match &self.on_view_change {
+                Some(message) => shell.publish(message(Some(x_view), Some(y_view))),
+                None => {
+                    if self.link.is_none() {
+                        state.x_view = Some(x_view);
+                    }
+                    state.y_view = Some(y_view);
+                    state.view_base = (self.x_range.clone(), self.y_range.clone());
+                }
+            }
+        }
+
+        self.cache.clear();
+        shell.invalidate_layout();
+        shell.request_redraw();
+
+        true
+    }
+
+    fn compute_x_range_from_series(&self) -> RangeInclusive<f32> {
+        let mut max: Option<RangeInclusive<f32>> = None;
+
+        let references = self.references.iter().filter_map(Reference::x_range);
+
+        for cur in self.series.iter().map(|s| s.x_range()).chain(references) {
+            max = match max {
+                Some(max) => {
+                    let min = max.start().min(*cur.start());
+                    let max = max.end().max(*cur.end());
+
+                    Some(min..=max)
+                }
+                None => Some(cur),
+            }
+        }
+
+        max.unwrap_or(Self::X_RANGE_DEFAULT)
+    }
+
+    /// The computed x range with room for one more spoke, since a full turn
+    /// ends where it started.
+    fn compute_polar_x_range(&self) -> RangeInclusive<f32> {
+        let range = self.compute_x_range_from_series();
+        let length = range.end() - range.start();
+
+        let spokes = self.x_ticks.amount;
+        let step = if spokes > 1 {
+            length / (spokes - 1) as f32
+        } else {
+            length
+        };
+        let step = if step > 0.0 { step } else { 1.0 };
+
+        *range.start()..=range.end() + step
+    }
+
+    fn compute_y_range_from_series(&self) -> RangeInclusive<f32> {
+        let mut max: Option<RangeInclusive<f32>> = None;
+
+        let references = self.references.iter().filter_map(Reference::y_range);
+
+        for cur in self.series.iter().map(|s| s.y_range()).chain(references) {
+            max = match max {
+                Some(max) => {
+                    let min = max.start().min(*cur.start());
+                    let max = max.end().max(*cur.end());
+
+                    Some(min..=max)
+                }
+                None => Some(cur),
+            }
+        }
+
+        max.unwrap_or(Self::Y_RANGE_DEFAULT)
+    }
+}
+
+impl<Message, Id, Theme> Widget<Message, Theme, Renderer> for Chart<'_, Message, Id, Theme>
+where
+    Message: Clone,
+    Id: 'static + Clone + PartialEq,
+    Theme: Catalog,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State<Id>>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::<Id>::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![]
+    }
+
+    #[inline]
+    fn layout(
+        &self,
+        tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let node = layout::atomic(limits, self.width, self.height);
+        //limits.resolve(self.width, self.height, Size::ZERO);
+
+        if self.link.is_some() {
+            // the shared range might have been moved by another chart
+            self.cache.clear();
+        }
+
+        let state = tree.state.downcast_mut::<State<Id>>();
+        self.update_planes(state, node.bounds().size());
+
+        node
+    }
+
+    #[inline]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        let state: &State<Id> = tree.state.downcast_ref();
+        let style = theme.style(&self.class);
+        // kept for copying, which happens outside of draw and without a theme
+        if self.copies() && state.style.borrow().as_ref() != Some(&style) {
+            state.style.replace(Some(style.clone()));
+        }
+
+        Self::draw_background(renderer, bounds, state, &style);
+
+        if state.plane.is_none() && state.polar.is_none() {
+            return;
+        }
+
+        let geometry = self.cache.draw(renderer, bounds.size(), |frame| {
+            self.draw_on(frame, state, &style);
+        });
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(geometry)
+        });
+
+        if let Some(area) = state.area.as_ref().filter(|area| area.len() > 1) {
+            let mut frame = canvas::Frame::new(renderer, bounds.size());
+
+            let path = Path::new(|b| {
+                b.move_to(area[0]);
+                for point in &area[1..] {
+                    b.line_to(*point);
+                }
+                b.close();
+            });
+            frame.fill(&path, style.axis.scale_alpha(0.1));
+            frame.stroke(
+                &path,
+                Stroke::default().with_width(1.0).with_color(style.axis),
+            );
+
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw_geometry(frame.into_geometry())
+            });
+        }
+
+        if state.is_focused {
+            let mut frame = canvas::Frame::new(renderer, bounds.size());
+            let stroke = Stroke::default().with_width(1.0).with_color(style.axis);
+
+            frame.stroke(
+                &Path::rectangle(
+                    Point::new(0.5, 0.5),
+                    Size::new(bounds.width - 1.0, bounds.height - 1.0),
+                ),
+                stroke,
+            );
+
+            if let (Some(point), Some(coordinates)) = (state.stepped, state.coordinates()) {
+                frame.stroke(
+                    &Path::circle(coordinates.to_screen(point), Self::STEP_MARKER_RADIUS),
+                    stroke.with_width(2.0),
+                );
+            }
+
+            renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+                renderer.draw_geometry(frame.into_geometry())
+            });
+        }
+
+        let (Some(link), Some(plane)) = (&self.link, &state.plane) else {
+            return;
+        };
+        let Some(x) = link.cursor_x() else {
+            return;
+        };
+        if x < plane.x.min || x > plane.x.max {
+            return;
+        }
+
+        // drawn outside of the cache, as the cursor moves in other charts too
+        let mut frame = canvas::Frame::new(renderer, bounds.size());
+        let x = plane.scale_to_cartesian_x(x);
+        frame.stroke(
+            &Path::line(
+                Point::new(x, plane.scale_to_cartesian_y(plane.y.max)),
+                Point::new(x, plane.scale_to_cartesian_y(plane.y.min)),
+            ),
+            Stroke::default()
+                .with_width(self.x_axis.width)
+                .with_color(self.x_axis.color.unwrap_or(style.axis).scale_alpha(0.5)),
+        );
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(frame.into_geometry())
+        });
     }
 
     fn update(
@@ -564,21 +1967,65 @@ where
         layout: Layout<'_>,
         cursor: mouse::Cursor,
         _renderer: &Renderer,
-        _clipboard: &mut dyn Clipboard,
+        clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
         _viewport: &Rectangle,
     ) {
+        let bounds = layout.bounds();
+
+        let state: &mut State<Id> = tree.state.downcast_mut();
+
+        if let Some(link) = &self.link {
+            Self::update_link(link, state, event, bounds, cursor, shell);
+        }
+
+        if let iced::Event::Mouse(mouse::Event::ButtonPressed(_))
+        | iced::Event::Touch(touch::Event::FingerPressed { .. }) = event
+        {
+            state.is_focused = cursor.is_over(bounds);
+        }
+
+        if let iced::Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) = event {
+            state.modifiers = *modifiers;
+        }
+
+        if let iced::Event::Window(window::Event::Rescaled(scale_factor)) = event {
+            state.scale_factor = *scale_factor;
+        }
+
+        if let iced::Event::Keyboard(keyboard::Event::KeyPressed { key, modifiers, .. }) = event {
+            if state.is_focused && self.update_keyboard(state, key, *modifiers, shell) {
+                shell.capture_event();
+
+                return;
+            }
+
+            if modifiers.command()
+                && matches!(key.as_ref(), keyboard::Key::Character(c) if c.eq_ignore_ascii_case("c"))
+                && cursor.is_over(bounds)
+                && self.copies()
+            {
+                if let Some(message) = self.copy_to(clipboard, state, bounds.size()) {
+                    shell.publish(message);
+                }
+                shell.capture_event();
+
+                return;
+            }
+        }
+
         let Some(cursor_position) = cursor.position() else {
+            self.update_hover(state, false, shell);
+
             return;
         };
 
-        let bounds = layout.bounds();
-
-        let state: &mut State<Id> = tree.state.downcast_mut();
         let relative_position = cursor_position - Vector::new(bounds.x, bounds.y);
         state.prev_position = state.cursor_position;
         state.cursor_position = Some(relative_position);
 
+        self.update_pointer(state, event, bounds, shell);
+
         //if state.cursor_position != cursor_position || state.bounds != bounds {
         if bounds.contains(cursor_position) {
             if let Some(message) = self.on_press.as_ref() {
@@ -605,33 +2052,14 @@ where
                 if let iced::Event::Mouse(mouse::Event::CursorMoved { .. })
                 | iced::Event::Touch(touch::Event::FingerMoved { .. }) = event
                 {
-                    if let (Some(coords), Some(plane)) = (state.get_coords(), &state.plane) {
-                        let iter = self
-                            .series
-                            .iter()
-                            .filter_map(|s| s.id().map(|id| (id, s.collision_box().unwrap())));
-
-                        let mut item_list = vec![];
-                        for (series_id, collision_box) in iter {
-                            let top_left = Point::new(
-                                coords.x - collision_box.width / 2.0 / plane.x.scale,
-                                coords.y - collision_box.height / 2.0 / plane.y.scale,
-                            );
-                            let rect = Rectangle::new(
-                                top_left,
-                                Size::new(
-                                    collision_box.width / plane.x.scale,
-                                    collision_box.height / plane.y.scale,
-                                ),
-                            );
-                            item_list.extend(
-                                self.items
-                                    .collision(rect)
-                                    .into_iter()
-                                    .filter(|i| i.0 == series_id),
-                            );
-                        }
+                    if let (Some(position), Some(coordinates)) =
+                        (state.cursor_position, state.coordinates())
+                    {
+                        let (item_list, line_hits) = self.hovered_at(coordinates, position);
+
                         state.item_list = Some(item_list);
+                        state.stepped = None;
+                        state.line_hits = Some(line_hits);
                     }
 
                     shell.publish(message(state));
@@ -650,6 +2078,18 @@ where
         }
     }
 
+    fn operate(
+        &self,
+        tree: &mut Tree,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+        operation: &mut dyn Operation,
+    ) {
+        let state = tree.state.downcast_mut::<State<Id>>();
+
+        operation.focusable(self.id.as_ref(), layout.bounds(), state);
+    }
+
     fn mouse_interaction(
         &self,
         _tree: &Tree,
@@ -666,6 +2106,7 @@ impl<Message, Id, Theme> Default for Chart<'_, Message, Id, Theme>
 where
     Message: Clone,
     Id: Clone,
+    Theme: Catalog,
 {
     fn default() -> Self {
         Chart::new()
@@ -678,10 +2119,34 @@ where
     Id: Clone,
 {
     plane: Option<Plane>,
+    polar: Option<polar::Plane>,
     prev_position: Option<Point>,
     cursor_position: Option<Point>,
     scroll_delta: Option<ScrollDelta>,
     item_list: Option<Vec<(Id, usize)>>,
+    /// Where the item stepped to with `Tab` is located, in data space.
+    stepped: Option<Point>,
+    line_hits: Option<Vec<LineHit<Id>>>,
+    link_hovered: bool,
+    pan_origin: Option<(Point, RangeInclusive<f32>)>,
+    is_focused: bool,
+    last_click: Option<mouse::Click>,
+    drag: Option<Drag>,
+    grab: Option<Grab<Id>>,
+    modifiers: keyboard::Modifiers,
+    /// Outline of the area being selected, relative to the chart.
+    area: Option<Vec<Point>>,
+    plot_hovered: bool,
+    /// Ranges panned or zoomed to with the keyboard.
+    x_view: Option<RangeInclusive<f32>>,
+    y_view: Option<RangeInclusive<f32>>,
+    /// The ranges of the app when the view was set, changing them resets it.
+    view_base: (Option<RangeInclusive<f32>>, Option<RangeInclusive<f32>>),
+    /// Scale factor of the window, for copied images.
+    scale_factor: f32,
+    /// Style of the last draw if copying is set up, updated only when it
+    /// changes.
+    style: RefCell<Option<Style>>,
 }
 
 impl<Id> State<Id>
@@ -692,8 +2157,17 @@ where
         self.cursor_position
     }
 
+    /// The coordinate system of the last layout, if any.
+    pub fn coordinates(&self) -> Option<&dyn CoordinateSystem> {
+        match (&self.polar, &self.plane) {
+            (Some(polar), _) => Some(polar),
+            (None, Some(plane)) => Some(plane),
+            (None, None) => None,
+        }
+    }
+
     fn get_cartesian(&self, point: Point) -> Option<Point> {
-        self.plane.as_ref().map(|p| p.get_cartesian(point))
+        self.coordinates().map(|c| c.to_data(point))
     }
 
     pub fn get_coords(&self) -> Option<Point> {
@@ -719,9 +2193,67 @@ where
         self.scroll_delta
     }
 
+    /// The items under the cursor as `(series id, item index)`, sorted by
+    /// distance unless the [`Hover`] mode is [`Hover::Collision`].
+    ///
+    /// The index is the one of the series data, heatmap cells are numbered
+    /// row by row, see [`series::HeatmapSeries::cell`]. Lines are reported by
+    /// [`Self::line_hits`] instead.
     pub fn items(&self) -> Option<&Vec<(Id, usize)>> {
         self.item_list.as_ref()
     }
+
+    /// The lines under the cursor, with their values at the cursor, sorted
+    /// like [`Self::items`].
+    pub fn line_hits(&self) -> Option<&Vec<LineHit<Id>>> {
+        self.line_hits.as_ref()
+    }
+
+    /// Where the current drag started, in data space.
+    pub fn drag_origin(&self) -> Option<Point> {
+        let drag = self.drag.as_ref().filter(|drag| drag.started)?;
+
+        self.get_cartesian(drag.origin)
+    }
+
+    /// How far the cursor moved in data space since the current drag started.
+    pub fn drag_delta(&self) -> Option<Vector> {
+        Some(self.get_coords()? - self.drag_origin()?)
+    }
+
+    /// The ranges panned or zoomed to with the keyboard.
+    fn view(&self) -> View {
+        View {
+            x: self.x_view.clone(),
+            y: self.y_view.clone(),
+        }
+    }
+
+    /// Whether the position, relative to the chart, lies within the plot area.
+    fn is_in_plot(&self, position: Point) -> bool {
+        match (&self.polar, &self.plane) {
+            (Some(polar), _) => polar.center.distance(position) <= polar.radius,
+            (None, Some(plane)) => plane.area().contains(position),
+            (None, None) => false,
+        }
+    }
+}
+
+impl<Id> operation::Focusable for State<Id>
+where
+    Id: Clone,
+{
+    fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
 }
 
 impl<Id> Default for State<Id>
@@ -731,10 +2263,27 @@ where
     fn default() -> Self {
         Self {
             plane: Default::default(),
+            polar: Default::default(),
             prev_position: Default::default(),
             cursor_position: Default::default(),
             scroll_delta: Default::default(),
             item_list: Default::default(),
+            stepped: Default::default(),
+            line_hits: Default::default(),
+            link_hovered: Default::default(),
+            pan_origin: Default::default(),
+            is_focused: Default::default(),
+            last_click: Default::default(),
+            drag: Default::default(),
+            grab: Default::default(),
+            modifiers: Default::default(),
+            area: Default::default(),
+            plot_hovered: Default::default(),
+            x_view: Default::default(),
+            y_view: Default::default(),
+            view_base: Default::default(),
+            scale_factor: 1.0,
+            style: Default::default(),
         }
     }
 }
@@ -742,7 +2291,7 @@ where
 impl<'a, Message, Id, Theme> From<Chart<'a, Message, Id, Theme>> for Element<'a, Message, Theme>
 where
     Message: 'a + Clone,
-    Theme: 'a,
+    Theme: 'a + Catalog,
     Id: 'static + Clone + PartialEq,
 {
     fn from(chart: Chart<'a, Message, Id, Theme>) -> Element<'a, Message, Theme, Renderer> {
@@ -750,6 +2299,176 @@ where
     }
 }
 
+/// The shape of a [`Chart::selection_area`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionArea {
+    Rectangle,
+    /// A freehand outline following the cursor.
+    Lasso,
+}
+
+/// How a [`Chart`] finds the items under the cursor, and the lines in
+/// [`State::line_hits`] alike.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Hover {
+    /// All items within the collision box of their series.
+    #[default]
+    Collision,
+    /// The items within `radius` pixels, nearest first.
+    Nearest { radius: f32 },
+    /// The items of all series at the x nearest to the cursor, within
+    /// `radius` pixels horizontally, nearest first. Lines are hit at the same
+    /// x. Suits tooltips of time series.
+    NearestX { radius: f32 },
+}
+
+/// The segment of a line under the cursor, see [`State::line_hits`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineHit<Id> {
+    pub series: Id,
+    /// Index of the first point of the segment.
+    pub segment: usize,
+    /// The point on the segment at the x of the cursor.
+    pub value: Point,
+}
+
+/// The region in data space covered by a box of pixels centered at the
+/// position.
+fn data_rect(coordinates: &dyn CoordinateSystem, position: Point, size: Size) -> Rectangle {
+    let half = Vector::new(size.width / 2.0, size.height / 2.0);
+    let a = coordinates.to_data(position - half);
+    let b = coordinates.to_data(position + half);
+
+    Rectangle::new(
+        Point::new(a.x.min(b.x), a.y.min(b.y)),
+        Size::new((a.x - b.x).abs(), (a.y - b.y).abs()),
+    )
+}
+
+/// An open path through the points, in order.
+fn polyline(points: impl IntoIterator<Item = Point>) -> Path {
+    Path::new(|b| {
+        for (i, point) in points.into_iter().enumerate() {
+            if i == 0 {
+                b.move_to(point);
+            } else {
+                b.line_to(point);
+            }
+        }
+    })
+}
+
+/// The point of the segment from `a` to `b` at `x`, clamped to its ends.
+fn value_at(a: Point, b: Point, x: f32) -> Point {
+    if a.x == b.x {
+        return a;
+    }
+
+    let t = ((x - a.x) / (b.x - a.x)).clamp(0.0, 1.0);
+
+    a + (b - a) * t
+}
+
+/// Horizontal and vertical distance in pixels from the position to the screen
+/// bounds of an item, zero inside.
+fn screen_distance(
+    coordinates: &dyn CoordinateSystem,
+    bounds: Rectangle,
+    position: Point,
+) -> (f32, f32) {
+    let a = coordinates.to_screen(bounds.position());
+    let b = coordinates.to_screen(bounds.position() + Vector::new(bounds.width, bounds.height));
+
+    let dx = (a.x.min(b.x) - position.x)
+        .max(position.x - a.x.max(b.x))
+        .max(0.0);
+    let dy = (a.y.min(b.y) - position.y)
+        .max(position.y - a.y.max(b.y))
+        .max(0.0);
+
+    (dx, dy)
+}
+
+/// The selection after clicking or enclosing the items: with Ctrl (Cmd on
+/// macOS) held they are toggled, with Shift added, otherwise they replace it.
+fn combine<Id>(
+    selection: &[(Id, usize)],
+    items: Vec<(Id, usize)>,
+    modifiers: keyboard::Modifiers,
+) -> Vec<(Id, usize)>
+where
+    Id: Clone + PartialEq,
+{
+    if !modifiers.command() && !modifiers.shift() {
+        return items;
+    }
+
+    let mut selection = selection.to_vec();
+    for item in items {
+        match selection.iter().position(|i| *i == item) {
+            Some(index) if modifiers.command() => {
+                selection.remove(index);
+            }
+            Some(_) => {}
+            None => selection.push(item),
+        }
+    }
+
+    selection
+}
+
+/// Whether the point lies within the polygon, by counting the edges a ray to
+/// the right crosses.
+fn contains(polygon: &[Point], point: Point) -> bool {
+    let mut inside = false;
+
+    for (i, a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x)
+        {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+/// Ranges a chart has been panned or zoomed to, overriding the ranges of the
+/// app.
+#[derive(Default)]
+struct View {
+    x: Option<RangeInclusive<f32>>,
+    y: Option<RangeInclusive<f32>>,
+}
+
+/// A press of the left button, which becomes a drag once the cursor moved far
+/// enough.
+struct Drag {
+    origin: Point,
+    started: bool,
+}
+
+/// An item held with the left button.
+struct Grab<Id> {
+    item: (Id, usize),
+    /// Location of the item when it was grabbed.
+    origin: Point,
+    /// Cursor in data space when the item was grabbed.
+    cursor: Point,
+    constraints: drag::Constraints,
+}
+
+/// What [`Chart::copy`] puts on the clipboard.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// The visible data of each series as tab separated values.
+    Data,
+    /// The chart as an SVG document.
+    Svg,
+}
+
 pub struct Margin {
     pub top: f32,
     pub bottom: f32,
@@ -771,3 +2490,84 @@ impl Default for Margin {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use keyboard::Modifiers;
+
+    #[test]
+    fn polygon_contains() {
+        let square = [
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+
+        assert!(contains(&square, Point::new(2.0, 2.0)));
+        assert!(!contains(&square, Point::new(5.0, 2.0)));
+        assert!(!contains(&square, Point::new(-1.0, 2.0)));
+        assert!(!contains(&square[..2], Point::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn concave_polygon_contains() {
+        // a U shape, open at the top between x 1 and 3
+        let u = [
+            Point::new(0.0, 0.0),
+            Point::new(4.0, 0.0),
+            Point::new(4.0, 4.0),
+            Point::new(3.0, 4.0),
+            Point::new(3.0, 1.0),
+            Point::new(1.0, 1.0),
+            Point::new(1.0, 4.0),
+            Point::new(0.0, 4.0),
+        ];
+
+        assert!(contains(&u, Point::new(0.5, 3.0)));
+        assert!(contains(&u, Point::new(3.5, 3.0)));
+        assert!(contains(&u, Point::new(2.0, 0.5)));
+        assert!(!contains(&u, Point::new(2.0, 3.0)));
+    }
+
+    #[test]
+    fn click_replaces_selection() {
+        let selection = [(0, 1), (0, 2)];
+
+        assert_eq!(
+            combine(&selection, vec![(1, 0)], Modifiers::empty()),
+            [(1, 0)]
+        );
+        assert!(combine(&selection, vec![], Modifiers::empty()).is_empty());
+    }
+
+    #[test]
+    fn shift_adds_to_selection() {
+        let selection = [(0, 1), (0, 2)];
+
+        assert_eq!(
+            combine(&selection, vec![(0, 2), (0, 3)], Modifiers::SHIFT),
+            [(0, 1), (0, 2), (0, 3)]
+        );
+    }
+
+    #[test]
+    fn command_toggles_selection() {
+        let selection = [(0, 1), (0, 2)];
+
+        assert_eq!(
+            combine(&selection, vec![(0, 2), (0, 3)], Modifiers::COMMAND),
+            [(0, 1), (0, 3)]
+        );
+        assert_eq!(
+            combine(
+                &selection,
+                vec![(0, 1)],
+                Modifiers::COMMAND | Modifiers::SHIFT
+            ),
+            [(0, 2)]
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use iced::{
+    Color, Font, Pixels, Point, Vector, alignment,
+    widget::canvas::{self, Path, Stroke},
+};
+
+use super::{CoordinateSystem, Target, polyline};
+
+/// A single coordinate of an [`Anchor`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Coordinate {
+    /// A value in data space.
+    Data(f32),
+    /// Pixels from the top left corner of the chart.
+    Pixel(f32),
+}
+
+/// Where an annotation is attached to.
+///
+/// Both coordinates can be chosen independently, e.g. a label at some data
+/// `x` which always sits 10 pixels below the top of the chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anchor {
+    pub x: Coordinate,
+    pub y: Coordinate,
+}
+
+impl Anchor {
+    pub fn new(x: Coordinate, y: Coordinate) -> Self {
+        Self { x, y }
+    }
+
+    pub fn data(x: f32, y: f32) -> Self {
+        Self::new(Coordinate::Data(x), Coordinate::Data(y))
+    }
+
+    pub fn pixel(x: f32, y: f32) -> Self {
+        Self::new(Coordinate::Pixel(x), Coordinate::Pixel(y))
+    }
+
+    fn to_screen(self, coordinates: &dyn CoordinateSystem) -> Point {
+        let bounds = coordinates.bounds();
+
+        let data = |coordinate, fallback| match coordinate {
+            Coordinate::Data(value) => value,
+            Coordinate::Pixel(_) => fallback,
+        };
+        let screen =
+            coordinates.to_screen(Point::new(data(self.x, bounds.x), data(self.y, bounds.y)));
+
+        let pixel = |coordinate, projected| match coordinate {
+            Coordinate::Data(_) => projected,
+            Coordinate::Pixel(value) => value,
+        };
+        Point::new(pixel(self.x, screen.x), pixel(self.y, screen.y))
+    }
+}
+
+impl From<(f32, f32)> for Anchor {
+    fn from((x, y): (f32, f32)) -> Self {
+        Self::data(x, y)
+    }
+}
+
+impl From<Point> for Anchor {
+    fn from(point: Point) -> Self {
+        Self::data(point.x, point.y)
+    }
+}
+
+/// Whether an annotation is drawn below or above the series.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layer {
+    Below,
+    #[default]
+    Above,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    Text {
+        anchor: Anchor,
+        content: String,
+        align_x: alignment::Horizontal,
+        align_y: alignment::Vertical,
+    },
+    Arrow {
+        from: Anchor,
+        to: Anchor,
+        /// Length of the head in pixels.
+        head: f32,
+    },
+    /// Spans between two opposite corners.
+    Rectangle {
+        from: Anchor,
+        to: Anchor,
+    },
+    Circle {
+        center: Anchor,
+        /// Radius in pixels.
+        radius: f32,
+    },
+    Polyline {
+        points: Vec<Anchor>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// Color of the text and outlines, defaults to the label color of the
+    /// chart style.
+    pub color: Option<Color>,
+    pub fill: Option<Color>,
+    pub width: f32,
+    pub font_size: Pixels,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            color: None,
+            fill: None,
+            width: 1.0,
+            font_size: 12.into(),
+        }
+    }
+}
+
+/// Text, arrows and shapes drawn on top of (or below) the series, see
+/// [`crate::chart::Chart::push_annotation`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Annotation {
+    pub kind: Kind,
+    pub style: Style,
+    pub layer: Layer,
+}
+
+impl Annotation {
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            style: Style::default(),
+            layer: Layer::default(),
+        }
+    }
+
+    pub fn text(anchor: impl Into<Anchor>, content: impl Into<String>) -> Self {
+        Self::new(Kind::Text {
+            anchor: anchor.into(),
+            content: content.into(),
+            align_x: alignment::Horizontal::Left,
+            align_y: alignment::Vertical::Bottom,
+        })
+    }
+
+    pub fn arrow(from: impl Into<Anchor>, to: impl Into<Anchor>) -> Self {
+        Self::new(Kind::Arrow {
+            from: from.into(),
+            to: to.into(),
+            head: 8.0,
+        })
+    }
+
+    pub fn rectangle(from: impl Into<Anchor>, to: impl Into<Anchor>) -> Self {
+        Self::new(Kind::Rectangle {
+            from: from.into(),
+            to: to.into(),
+        })
+    }
+
+    pub fn circle(center: impl Into<Anchor>, radius: f32) -> Self {
+        Self::new(Kind::Circle {
+            center: center.into(),
+            radius,
+        })
+    }
+
+    pub fn polyline(points: impl IntoIterator<Item = impl Into<Anchor>>) -> Self {
+        Self::new(Kind::Polyline {
+            points: points.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.style.color = Some(color.into());
+        self
+    }
+
+    pub fn fill(mut self, color: impl Into<Color>) -> Self {
+        self.style.fill = Some(color.into());
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.style.width = width;
+        self
+    }
+
+    pub fn font_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.style.font_size = size.into();
+        self
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Draws the annotation below the series.
+    pub fn below(self) -> Self {
+        self.layer(Layer::Below)
+    }
+
+    /// Aligns text annotations relative to their anchor, ignored by the other
+    /// kinds.
+    pub fn align(
+        mut self,
+        horizontal: alignment::Horizontal,
+        vertical: alignment::Vertical,
+    ) -> Self {
+        if let Kind::Text {
+            align_x, align_y, ..
+        } = &mut self.kind
+        {
+            *align_x = horizontal;
+            *align_y = vertical;
+        }
+        self
+    }
+
+    pub(crate) fn draw(
+        &self,
+        frame: &mut dyn Target,
+        coordinates: &dyn CoordinateSystem,
+        default_color: Color,
+    ) {
+        let color = self.style.color.unwrap_or(default_color);
+        let stroke = Stroke::default()
+            .with_width(self.style.width)
+            .with_color(color);
+
+        let path = match &self.kind {
+            Kind::Text {
+                anchor,
+                content,
+                align_x,
+                align_y,
+            } => {
+                frame.fill_text(canvas::Text {
+                    content: content.clone(),
+                    size: self.style.font_size,
+                    position: anchor.to_screen(coordinates),
+                    color,
+                    align_x: *align_x,
+                    align_y: *align_y,
+                    font: Font::MONOSPACE,
+                    ..canvas::Text::default()
+                });
+
+                return;
+            }
+            Kind::Arrow { from, to, head } => {
+                let from = from.to_screen(coordinates);
+                let to = to.to_screen(coordinates);
+
+                let direction = to - from;
+                let length = direction.x.hypot(direction.y);
+
+                Path::new(|b| {
+                    b.move_to(from);
+                    b.line_to(to);
+
+                    if length > 0.0 {
+                        let back = Vector::new(direction.x, direction.y) * (head / length);
+                        // about 25 degrees to either side of the shaft
+                        let (sin, cos) = 0.45_f32.sin_cos();
+                        let left =
+                            Vector::new(back.x * cos - back.y * sin, back.x * sin + back.y * cos);
+                        let right =
+                            Vector::new(back.x * cos + back.y * sin, -back.x * sin + back.y * cos);
+
+                        b.move_to(to - left);
+                        b.line_to(to);
+                        b.line_to(to - right);
+                    }
+                })
+            }
+            Kind::Rectangle { from, to } => {
+                let corners = [
+                    *from,
+                    Anchor::new(to.x, from.y),
+                    *to,
+                    Anchor::new(from.x, to.y),
+                ];
+
+                Path::new(|b| {
+                    for (i, corner) in corners.iter().enumerate() {
+                        let point = corner.to_screen(coordinates);
+                        if i == 0 {
+                            b.move_to(point);
+                        } else {
+                            b.line_to(point);
+                        }
+                    }
+                    b.close();
+                })
+            }
+            Kind::Circle { center, radius } => Path::circle(center.to_screen(coordinates), *radius),
+            Kind::Polyline { points } => {
+                polyline(points.iter().map(|anchor| anchor.to_screen(coordinates)))
+            }
+        };
+
+        if let Some(fill) = self.style.fill {
+            frame.fill(
+                &path,
+                canvas::Fill {
+                    style: canvas::Style::Solid(fill),
+                    ..Default::default()
+                },
+            );
+        }
+
+        frame.stroke(&path, stroke);
+    }
+}
@@ -5,14 +5,14 @@ pub use label::Labels;
 pub use tick::Tick;
 
 pub struct Axis {
-    pub color: iced::Color,
+    pub color: Option<iced::Color>,
     pub width: f32,
     // TODO limits
 }
 
 impl Axis {
     pub fn color(mut self, color: iced::Color) -> Self {
-        self.color = color;
+        self.color = Some(color);
         self
     }
 
@@ -25,8 +25,7 @@ impl Axis {
 impl Default for Axis {
     fn default() -> Self {
         Self {
-            // use color from theme
-            color: iced::Color::WHITE,
+            color: None,
             width: 1.0,
         }
     }
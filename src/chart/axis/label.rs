@@ -2,6 +2,7 @@
 pub struct Labels<'a> {
     pub color: Option<iced::Color>,
     pub font_size: Option<iced::Pixels>,
+    pub hidden: bool,
     pub format: Option<&'a dyn Fn(&f32) -> String>, // TODO:
                                                     // alignment
                                                     // limits
@@ -39,4 +40,9 @@ impl<'a> Labels<'a> {
         self.format = Some(format);
         self
     }
+
+    pub fn hidden(mut self, hidden: bool) -> Self {
+        self.hidden = hidden;
+        self
+    }
 }
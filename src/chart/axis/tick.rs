@@ -1,5 +1,5 @@
 pub struct Tick {
-    pub color: iced::Color,
+    pub color: Option<iced::Color>,
     pub height: f32,
     pub width: f32,
     pub amount: usize,
@@ -12,7 +12,7 @@ pub struct Tick {
 
 impl Tick {
     pub fn color(mut self, color: iced::Color) -> Self {
-        self.color = color;
+        self.color = Some(color);
         self
     }
 
@@ -35,8 +35,7 @@ impl Tick {
 impl Default for Tick {
     fn default() -> Self {
         Self {
-            // use color from theme
-            color: iced::Color::WHITE,
+            color: None,
             height: 5.0,
             width: 1.0,
             amount: 10,
@@ -1,5 +1,7 @@
 use std::{f32, ops::RangeInclusive};
 
+use super::CoordinateSystem;
+
 pub struct Plane {
     pub x: Axis,
     pub y: Axis,
@@ -51,14 +53,10 @@ impl Plane {
     }
 
     pub fn get_cartesian(&self, pos: iced::Point) -> iced::Point {
-        let mut point =
-            pos * iced::Transformation::translate(-self.x.margin_min, -self.y.margin_min);
-        point.x /= self.x.scale;
-        point.y /= self.y.scale;
-        let mut point = point * iced::Transformation::translate(self.x.min, -self.y.max);
-        point.y = -point.y;
-
-        point
+        iced::Point {
+            x: (pos.x - self.x.margin_min) / self.x.scale + self.x.min,
+            y: self.y.max - (pos.y - self.y.margin_max) / self.y.scale,
+        }
     }
 
     pub fn get_offset(&self, pos: iced::Point) -> iced::Point {
@@ -76,6 +74,40 @@ impl Plane {
             y: self.scale_to_cartesian_y(point.y),
         }
     }
+
+    /// The plot area on the frame, i.e. the bounds without the margins.
+    pub fn area(&self) -> iced::Rectangle {
+        let top_left = iced::Point::new(
+            self.scale_to_cartesian_x(self.x.min),
+            self.scale_to_cartesian_y(self.y.max),
+        );
+        let bottom_right = iced::Point::new(
+            self.scale_to_cartesian_x(self.x.max),
+            self.scale_to_cartesian_y(self.y.min),
+        );
+
+        iced::Rectangle::new(
+            top_left,
+            iced::Size::new(bottom_right.x - top_left.x, bottom_right.y - top_left.y),
+        )
+    }
+}
+
+impl CoordinateSystem for Plane {
+    fn to_screen(&self, point: iced::Point) -> iced::Point {
+        self.scale_to_cartesian(point)
+    }
+
+    fn to_data(&self, position: iced::Point) -> iced::Point {
+        self.get_cartesian(position)
+    }
+
+    fn bounds(&self) -> iced::Rectangle {
+        iced::Rectangle::new(
+            iced::Point::new(self.x.min, self.y.min),
+            iced::Size::new(self.x.length, self.y.length),
+        )
+    }
 }
 
 pub struct Axis {
@@ -0,0 +1,99 @@
+//! Mapping of normalized values to colors.
+use iced::Color;
+
+pub enum Colormap<'a> {
+    /// Linear interpolation between `(position, color)` stops, positions in `0.0..=1.0`.
+    Gradient(Vec<(f32, Color)>),
+    Custom(&'a dyn Fn(f32) -> Color),
+}
+
+impl<'a> Colormap<'a> {
+    /// Evenly distributes the given colors over `0.0..=1.0`.
+    pub fn gradient(colors: impl IntoIterator<Item = Color>) -> Self {
+        let colors: Vec<_> = colors.into_iter().collect();
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+
+        Self::Gradient(
+            colors
+                .into_iter()
+                .enumerate()
+                .map(|(i, color)| (i as f32 / last, color))
+                .collect(),
+        )
+    }
+
+    pub fn custom(f: &'a dyn Fn(f32) -> Color) -> Self {
+        Self::Custom(f)
+    }
+
+    pub fn viridis() -> Self {
+        Self::gradient(
+            [
+                0x440154, 0x472d7b, 0x3b528b, 0x2c728e, 0x21918c, 0x28ae80, 0x5ec962, 0xaddc30,
+                0xfde725,
+            ]
+            .map(rgb),
+        )
+    }
+
+    pub fn magma() -> Self {
+        Self::gradient(
+            [
+                0x000004, 0x1c1044, 0x4f127b, 0x812581, 0xb5367a, 0xe55064, 0xfb8761, 0xfec287,
+                0xfcfdbf,
+            ]
+            .map(rgb),
+        )
+    }
+
+    /// A blue to red map around a light neutral center.
+    pub fn diverging() -> Self {
+        Self::gradient([0x3b4cc0, 0xdddddd, 0xb40426].map(rgb))
+    }
+
+    /// Color for a value in `0.0..=1.0`, values outside are clamped.
+    pub fn color(&self, value: f32) -> Color {
+        let value = value.clamp(0.0, 1.0);
+
+        match self {
+            Self::Custom(f) => f(value),
+            Self::Gradient(stops) => {
+                let Some(first) = stops.first() else {
+                    return Color::TRANSPARENT;
+                };
+
+                let upper = stops.partition_point(|(position, _)| *position < value);
+                if upper == 0 {
+                    return first.1;
+                }
+                let Some((end, to)) = stops.get(upper) else {
+                    return stops[stops.len() - 1].1;
+                };
+                let (start, from) = stops[upper - 1];
+
+                let t = if end > &start {
+                    (value - start) / (end - start)
+                } else {
+                    1.0
+                };
+
+                Color {
+                    r: from.r + (to.r - from.r) * t,
+                    g: from.g + (to.g - from.g) * t,
+                    b: from.b + (to.b - from.b) * t,
+                    a: from.a + (to.a - from.a) * t,
+                }
+            }
+        }
+    }
+}
+
+impl Default for Colormap<'_> {
+    fn default() -> Self {
+        Self::viridis()
+    }
+}
+
+fn rgb(hex: u32) -> Color {
+    Color::from_rgb8((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+}
@@ -0,0 +1,26 @@
+use iced::{Point, Rectangle};
+
+/// Maps between data space and the pixels of the chart frame.
+///
+/// Series only talk to the chart through this trait, which keeps them
+/// independent of the concrete projection.
+pub trait CoordinateSystem {
+    /// Maps a point in data space to a position on the frame.
+    fn to_screen(&self, point: Point) -> Point;
+
+    /// Maps a position on the frame back to data space.
+    fn to_data(&self, position: Point) -> Point;
+
+    /// The visible region in data space, `y` being the lower edge.
+    fn bounds(&self) -> Rectangle;
+
+    /// Whether the point lies within the visible region, edges included.
+    fn is_visible(&self, point: Point) -> bool {
+        let bounds = self.bounds();
+
+        point.x >= bounds.x
+            && point.x <= bounds.x + bounds.width
+            && point.y >= bounds.y
+            && point.y <= bounds.y + bounds.height
+    }
+}
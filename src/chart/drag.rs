@@ -0,0 +1,168 @@
+use iced::{Point, Vector};
+
+use std::ops::RangeInclusive;
+
+/// The directions a dragged item may move in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Direction {
+    #[default]
+    Both,
+    /// Only along the x axis.
+    Horizontal,
+    /// Only along the y axis.
+    Vertical,
+}
+
+/// Restricts where the items of a draggable series can be moved to, see
+/// [`crate::chart::Chart::on_item_drag`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    pub direction: Direction,
+    /// Grid spacing in data space, a zero component leaves that axis free.
+    pub snap: Option<Vector>,
+    pub x_range: Option<RangeInclusive<f32>>,
+    pub y_range: Option<RangeInclusive<f32>>,
+}
+
+impl Constraints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn x_only(mut self) -> Self {
+        self.direction = Direction::Horizontal;
+        self
+    }
+
+    pub fn y_only(mut self) -> Self {
+        self.direction = Direction::Vertical;
+        self
+    }
+
+    /// Snaps to multiples of the given spacing.
+    pub fn snap(mut self, x: f32, y: f32) -> Self {
+        self.snap = Some(Vector::new(x, y));
+        self
+    }
+
+    pub fn clamp_x(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = Some(range);
+        self
+    }
+
+    pub fn clamp_y(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = Some(range);
+        self
+    }
+
+    /// Where an item grabbed at `origin` ends up when dragged to `point`.
+    pub fn apply(&self, origin: Point, point: Point) -> Point {
+        let (free_x, free_y) = match self.direction {
+            Direction::Both => (true, true),
+            Direction::Horizontal => (true, false),
+            Direction::Vertical => (false, true),
+        };
+
+        let snap = |value: f32, step: f32| {
+            if step > 0.0 {
+                (value / step).round() * step
+            } else {
+                value
+            }
+        };
+        let clamp = |value: f32, range: &Option<RangeInclusive<f32>>| match range {
+            Some(range) => value.clamp(*range.start(), *range.end()),
+            None => value,
+        };
+
+        let step = self.snap.unwrap_or(Vector::ZERO);
+
+        let x = if free_x {
+            clamp(snap(point.x, step.x), &self.x_range)
+        } else {
+            origin.x
+        };
+        let y = if free_y {
+            clamp(snap(point.y, step.y), &self.y_range)
+        } else {
+            origin.y
+        };
+
+        Point::new(x, y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ORIGIN: Point = Point::new(1.0, 2.0);
+
+    #[test]
+    fn free_by_default() {
+        let point = Point::new(3.3, -4.4);
+
+        assert_eq!(Constraints::new().apply(ORIGIN, point), point);
+    }
+
+    #[test]
+    fn locked_axis_keeps_origin() {
+        let point = Point::new(3.0, 4.0);
+
+        assert_eq!(
+            Constraints::new().x_only().apply(ORIGIN, point),
+            Point::new(3.0, 2.0)
+        );
+        assert_eq!(
+            Constraints::new().y_only().apply(ORIGIN, point),
+            Point::new(1.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn snaps_to_multiples() {
+        let constraints = Constraints::new().snap(0.5, 2.0);
+
+        assert_eq!(
+            constraints.apply(ORIGIN, Point::new(1.3, 2.9)),
+            Point::new(1.5, 2.0)
+        );
+        assert_eq!(
+            constraints.apply(ORIGIN, Point::new(-0.8, 3.1)),
+            Point::new(-1.0, 4.0)
+        );
+    }
+
+    #[test]
+    fn zero_step_leaves_axis_free() {
+        let constraints = Constraints::new().snap(0.0, 1.0);
+
+        assert_eq!(
+            constraints.apply(ORIGIN, Point::new(1.3, 2.4)),
+            Point::new(1.3, 2.0)
+        );
+    }
+
+    #[test]
+    fn clamps_after_snapping() {
+        let constraints = Constraints::new()
+            .snap(1.0, 1.0)
+            .clamp_x(0.0..=2.5)
+            .clamp_y(-1.0..=1.0);
+
+        assert_eq!(
+            constraints.apply(ORIGIN, Point::new(2.9, -7.0)),
+            Point::new(2.5, -1.0)
+        );
+    }
+
+    #[test]
+    fn clamp_ignores_locked_axis() {
+        let constraints = Constraints::new().x_only().clamp_y(-1.0..=1.0);
+
+        assert_eq!(
+            constraints.apply(ORIGIN, Point::new(0.5, 0.0)),
+            Point::new(0.5, 2.0)
+        );
+    }
+}
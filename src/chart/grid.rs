@@ -0,0 +1,479 @@
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use iced::widget::{Column, Row};
+use iced::{Element, Length, Pixels};
+
+use super::{Catalog, Chart, State};
+
+type GridStateFn<'a, Message, Id> = Rc<dyn Fn(Cell, &State<Id>) -> Message + 'a>;
+type GridViewFn<'a, Message> = Rc<dyn Fn(ViewChange) -> Message + 'a>;
+
+/// Lays out several [`Chart`]s in rows and columns, optionally sharing their
+/// ranges.
+///
+/// Charts are filled in row by row. A keyboard pan or zoom of one chart is
+/// published by [`ChartGrid::on_view_change`] together with its groups, passing
+/// the new ranges back with [`ChartGrid::group_x_range`] and
+/// [`ChartGrid::group_y_range`] moves the whole group.
+///
+/// Mouse pans and zooms are left to the app, like for a single chart. The
+/// [`Cell`] passed to the interaction callbacks names the groups of the chart,
+/// so the new ranges can be passed back for the whole group in the same way.
+pub struct ChartGrid<'a, Message, Id, Theme = iced::Theme>
+where
+    Message: Clone,
+    Id: Clone,
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    spacing: f32,
+    columns: usize,
+
+    share_x: Share,
+    share_y: Share,
+
+    x_range: Option<RangeInclusive<f32>>,
+    y_range: Option<RangeInclusive<f32>>,
+    group_x_ranges: HashMap<usize, RangeInclusive<f32>>,
+    group_y_ranges: HashMap<usize, RangeInclusive<f32>>,
+
+    charts: Vec<Chart<'a, Message, Id, Theme>>,
+
+    on_move: Option<GridStateFn<'a, Message, Id>>,
+    on_press: Option<GridStateFn<'a, Message, Id>>,
+    on_release: Option<GridStateFn<'a, Message, Id>>,
+    on_scroll: Option<GridStateFn<'a, Message, Id>>,
+    on_view_change: Option<GridViewFn<'a, Message>>,
+}
+
+/// Which charts of a [`ChartGrid`] use a common range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Share {
+    #[default]
+    None,
+    All,
+    /// Charts in the same row.
+    Rows,
+    /// Charts in the same column.
+    Columns,
+}
+
+impl Share {
+    /// Whether charts stacked in a column use a common range.
+    fn spans_columns(self) -> bool {
+        matches!(self, Share::All | Share::Columns)
+    }
+
+    /// Whether charts side by side in a row use a common range.
+    fn spans_rows(self) -> bool {
+        matches!(self, Share::All | Share::Rows)
+    }
+
+    /// The group of the chart at the given row and column, numbered by row
+    /// for [`Share::Rows`] and by column for [`Share::Columns`].
+    fn group(self, row: usize, column: usize) -> Option<usize> {
+        match self {
+            Share::None => None,
+            Share::All => Some(0),
+            Share::Rows => Some(row),
+            Share::Columns => Some(column),
+        }
+    }
+}
+
+/// The chart of a [`ChartGrid`] an interaction happened in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    /// Index of the chart.
+    pub chart: usize,
+    /// Group of the chart sharing its x range, `None` if it isn't shared.
+    pub x_group: Option<usize>,
+    /// Group of the chart sharing its y range, `None` if it isn't shared.
+    pub y_group: Option<usize>,
+}
+
+/// A keyboard pan or zoom of one chart of a [`ChartGrid`], see
+/// [`ChartGrid::on_view_change`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ViewChange {
+    /// Index of the chart which was panned or zoomed.
+    pub chart: usize,
+    /// Group of the chart sharing its x range, `None` if it isn't shared.
+    pub x_group: Option<usize>,
+    /// Group of the chart sharing its y range, `None` if it isn't shared.
+    pub y_group: Option<usize>,
+    /// The new x range, `None` to go back to the computed one.
+    pub x_range: Option<RangeInclusive<f32>>,
+    /// The new y range, `None` to go back to the computed one.
+    pub y_range: Option<RangeInclusive<f32>>,
+}
+
+impl<'a, Message, Id, Theme> ChartGrid<'a, Message, Id, Theme>
+where
+    Message: Clone + 'a,
+    Id: Clone + 'a,
+    Theme: Catalog,
+{
+    pub fn new(columns: usize) -> Self {
+        Self {
+            width: Length::Fill,
+            height: Length::Fill,
+            spacing: 0.0,
+            columns: columns.max(1),
+            share_x: Share::default(),
+            share_y: Share::default(),
+            x_range: None,
+            y_range: None,
+            group_x_ranges: HashMap::new(),
+            group_y_ranges: HashMap::new(),
+            charts: Vec::new(),
+            on_move: None,
+            on_press: None,
+            on_release: None,
+            on_scroll: None,
+            on_view_change: None,
+        }
+    }
+
+    /// set width
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// set height
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    pub fn spacing(mut self, spacing: impl Into<Pixels>) -> Self {
+        self.spacing = spacing.into().0;
+        self
+    }
+
+    /// Shares the x range. When charts of a column share it, x labels are only
+    /// drawn on the bottom row.
+    pub fn share_x(mut self, share: Share) -> Self {
+        self.share_x = share;
+        self
+    }
+
+    /// Shares the y range. When charts of a row share it, y labels are only
+    /// drawn on the first column.
+    pub fn share_y(mut self, share: Share) -> Self {
+        self.share_y = share;
+        self
+    }
+
+    /// x range of every chart in a shared group, overriding the ranges of the
+    /// individual charts.
+    pub fn x_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = Some(range);
+        self
+    }
+
+    /// y range of every chart in a shared group, overriding the ranges of the
+    /// individual charts.
+    pub fn y_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = Some(range);
+        self
+    }
+
+    /// x range of a single group, e.g. from a [`ViewChange`], taking
+    /// precedence over [`Self::x_range`].
+    pub fn group_x_range(mut self, group: usize, range: RangeInclusive<f32>) -> Self {
+        self.group_x_ranges.insert(group, range);
+        self
+    }
+
+    /// y range of a single group, e.g. from a [`ViewChange`], taking
+    /// precedence over [`Self::y_range`].
+    pub fn group_y_range(mut self, group: usize, range: RangeInclusive<f32>) -> Self {
+        self.group_y_ranges.insert(group, range);
+        self
+    }
+
+    pub fn push_chart(mut self, chart: Chart<'a, Message, Id, Theme>) -> Self {
+        self.charts.push(chart);
+        self
+    }
+
+    pub fn extend_charts(
+        self,
+        charts: impl IntoIterator<Item = Chart<'a, Message, Id, Theme>>,
+    ) -> Self {
+        charts.into_iter().fold(self, Self::push_chart)
+    }
+
+    pub fn on_press(mut self, msg: impl Fn(Cell, &State<Id>) -> Message + 'a) -> Self {
+        self.on_press = Some(Rc::new(msg));
+        self
+    }
+
+    pub fn on_release(mut self, msg: impl Fn(Cell, &State<Id>) -> Message + 'a) -> Self {
+        self.on_release = Some(Rc::new(msg));
+        self
+    }
+
+    pub fn on_move(mut self, msg: impl Fn(Cell, &State<Id>) -> Message + 'a) -> Self {
+        self.on_move = Some(Rc::new(msg));
+        self
+    }
+
+    pub fn on_scroll(mut self, msg: impl Fn(Cell, &State<Id>) -> Message + 'a) -> Self {
+        self.on_scroll = Some(Rc::new(msg));
+        self
+    }
+
+    /// Publishes the pans and zooms of the charts instead of letting each
+    /// chart keep its own view, see [`Chart::on_view_change`].
+    pub fn on_view_change(mut self, msg: impl Fn(ViewChange) -> Message + 'a) -> Self {
+        self.on_view_change = Some(Rc::new(msg));
+        self
+    }
+
+    fn rows(&self) -> usize {
+        self.charts.len().div_ceil(self.columns)
+    }
+
+    /// Union of the ranges of every chart in each group.
+    fn shared_ranges(
+        &self,
+        share: Share,
+        range: impl Fn(&Chart<'a, Message, Id, Theme>) -> RangeInclusive<f32>,
+    ) -> Vec<Option<RangeInclusive<f32>>> {
+        let groups = self.rows().max(self.columns);
+        let mut ranges: Vec<Option<RangeInclusive<f32>>> = vec![None; groups];
+
+        for (index, chart) in self.charts.iter().enumerate() {
+            let Some(group) = share.group(index / self.columns, index % self.columns) else {
+                continue;
+            };
+
+            let cur = range(chart);
+            ranges[group] = match ranges[group].take() {
+                Some(max) => Some(max.start().min(*cur.start())..=max.end().max(*cur.end())),
+                None => Some(cur),
+            };
+        }
+
+        ranges
+    }
+
+    fn cell(&self, index: usize) -> Cell {
+        let (row, column) = (index / self.columns, index % self.columns);
+
+        Cell {
+            chart: index,
+            x_group: self.share_x.group(row, column),
+            y_group: self.share_y.group(row, column),
+        }
+    }
+
+    fn connect(
+        &self,
+        index: usize,
+        mut chart: Chart<'a, Message, Id, Theme>,
+    ) -> Chart<'a, Message, Id, Theme> {
+        let cell = self.cell(index);
+
+        if let Some(f) = self.on_move.clone() {
+            chart = chart.on_move(move |state| f(cell, state));
+        }
+        if let Some(f) = self.on_press.clone() {
+            chart = chart.on_press(move |state| f(cell, state));
+        }
+        if let Some(f) = self.on_release.clone() {
+            chart = chart.on_release(move |state| f(cell, state));
+        }
+        if let Some(f) = self.on_scroll.clone() {
+            chart = chart.on_scroll(move |state| f(cell, state));
+        }
+        if let Some(f) = self.on_view_change.clone() {
+            chart = chart.on_view_change(move |x_range, y_range| {
+                f(ViewChange {
+                    chart: cell.chart,
+                    x_group: cell.x_group,
+                    y_group: cell.y_group,
+                    x_range,
+                    y_range,
+                })
+            });
+        }
+
+        chart
+    }
+
+    /// Takes the charts out of the grid with the shared ranges applied and the
+    /// callbacks connected.
+    fn take_charts(&mut self) -> Vec<Chart<'a, Message, Id, Theme>> {
+        let x_ranges = match &self.x_range {
+            Some(range) => vec![Some(range.clone()); self.rows().max(self.columns)],
+            None => self.shared_ranges(self.share_x, |chart| match &chart.x_range {
+                Some(range) => range.clone(),
+                None => chart.compute_x_range_from_series(),
+            }),
+        };
+        let y_ranges = match &self.y_range {
+            Some(range) => vec![Some(range.clone()); self.rows().max(self.columns)],
+            None => self.shared_ranges(self.share_y, |chart| match &chart.y_range {
+                Some(range) => range.clone(),
+                None => chart.compute_y_range_from_series(),
+            }),
+        };
+
+        let x_ranges = with_groups(x_ranges, &self.group_x_ranges);
+        let y_ranges = with_groups(y_ranges, &self.group_y_ranges);
+
+        let columns = self.columns;
+        let charts = std::mem::take(&mut self.charts);
+        let count = charts.len();
+
+        charts
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut chart)| {
+                let cell = self.cell(index);
+
+                if let Some(group) = cell.x_group {
+                    chart.x_range = x_ranges[group].clone();
+                    // only the lowest chart of each column keeps its labels
+                    if self.share_x.spans_columns() && index + columns < count {
+                        chart.x_labels.hidden = true;
+                    }
+                }
+
+                if let Some(group) = cell.y_group {
+                    chart.y_range = y_ranges[group].clone();
+                    if self.share_y.spans_rows() && index % columns > 0 {
+                        chart.y_labels.hidden = true;
+                    }
+                }
+
+                self.connect(index, chart)
+            })
+            .collect()
+    }
+}
+
+impl<'a, Message, Id, Theme> From<ChartGrid<'a, Message, Id, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog,
+    Id: 'static + Clone + PartialEq,
+{
+    fn from(mut grid: ChartGrid<'a, Message, Id, Theme>) -> Element<'a, Message, Theme> {
+        let rows = grid.rows();
+        let columns = grid.columns;
+
+        let mut grid_rows: Vec<Vec<Element<'a, Message, Theme>>> =
+            (0..rows).map(|_| Vec::new()).collect();
+
+        for (index, chart) in grid.take_charts().into_iter().enumerate() {
+            grid_rows[index / columns].push(chart.into());
+        }
+
+        grid_rows
+            .into_iter()
+            .map(|children| {
+                Row::with_children(children)
+                    .spacing(grid.spacing)
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+            })
+            .fold(
+                Column::new()
+                    .spacing(grid.spacing)
+                    .width(grid.width)
+                    .height(grid.height),
+                Column::push,
+            )
+            .into()
+    }
+}
+
+/// Replaces the ranges of the groups set by the app.
+fn with_groups(
+    mut ranges: Vec<Option<RangeInclusive<f32>>>,
+    groups: &HashMap<usize, RangeInclusive<f32>>,
+) -> Vec<Option<RangeInclusive<f32>>> {
+    for (group, range) in groups {
+        if let Some(slot) = ranges.get_mut(*group) {
+            *slot = Some(range.clone());
+        }
+    }
+
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced::advanced::Shell;
+    use iced::{Size, keyboard};
+
+    use crate::chart::series::line_series;
+
+    /// Two rows of two charts, sharing the x range along each row.
+    fn grid<'a>() -> ChartGrid<'a, ViewChange, ()> {
+        ChartGrid::new(2)
+            .share_x(Share::Rows)
+            .on_view_change(|change| change)
+            .extend_charts(
+                (0..4)
+                    .map(|_| Chart::new().push_series(line_series(vec![(0.0, 0.0), (10.0, 1.0)]))),
+            )
+    }
+
+    #[test]
+    fn pan_moves_the_group() {
+        let charts = grid().take_charts();
+        let mut state = State::default();
+        charts[0].update_planes(&mut state, Size::new(200.0, 100.0));
+
+        let mut messages = Vec::new();
+        let key = keyboard::Key::Named(keyboard::key::Named::ArrowRight);
+        assert!(charts[0].update_keyboard(
+            &mut state,
+            &key,
+            keyboard::Modifiers::empty(),
+            &mut Shell::new(&mut messages),
+        ));
+
+        let [change] = messages.as_slice() else {
+            panic!("expected one view change, got {messages:?}");
+        };
+        assert_eq!((change.chart, change.x_group), (0, Some(0)));
+
+        let range = change.x_range.clone().unwrap();
+        assert_ne!(charts[1].x_range.as_ref(), Some(&range));
+
+        let charts = grid().group_x_range(0, range.clone()).take_charts();
+
+        assert_eq!(charts[0].x_range.as_ref(), Some(&range));
+        assert_eq!(charts[1].x_range.as_ref(), Some(&range));
+        assert_ne!(charts[2].x_range.as_ref(), Some(&range));
+        assert_ne!(charts[3].x_range.as_ref(), Some(&range));
+    }
+
+    #[test]
+    fn callbacks_get_the_groups() {
+        let grid = grid().share_y(Share::Columns);
+
+        assert_eq!(
+            grid.cell(3),
+            Cell {
+                chart: 3,
+                x_group: Some(1),
+                y_group: Some(1),
+            }
+        );
+        assert_eq!(grid.cell(2).x_group, Some(1));
+        assert_eq!(grid.cell(2).y_group, Some(0));
+    }
+}
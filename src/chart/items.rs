@@ -1,13 +1,19 @@
-use iced::Rectangle;
+//! Spatial index of the hoverable items of a chart.
+//!
+//! A [`Chart`](super::Chart) builds its index from scratch along with its
+//! series. [`Items`] can also be kept on its own, e.g. for large data sets
+//! changing a few items at a time, with [`Items::insert`], [`Items::update`]
+//! and [`Items::remove`].
+use iced::{Point, Rectangle, Size, Vector};
 use ordered_float::OrderedFloat;
 
-use std::collections::BTreeMap;
-
-type BTreeMapFloat<V> = BTreeMap<OrderedFloat<f32>, V>;
+use std::collections::HashMap;
 
 pub struct Entry<Id> {
     id: Id,
     location: iced::Point,
+    size: Size,
+    end: Option<Point>,
 }
 
 impl<Id> Entry<Id>
@@ -15,48 +21,553 @@ where
     Id: Clone,
 {
     pub fn new(id: Id, location: iced::Point) -> Self {
-        Self { id, location }
+        Self {
+            id,
+            location,
+            size: Size::ZERO,
+            end: None,
+        }
+    }
+
+    /// An item covering an area, like a bar, instead of a single point.
+    ///
+    /// `bounds` is given in data coordinates, with `bounds.y` being the lower edge.
+    pub fn area(id: Id, bounds: Rectangle) -> Self {
+        Self {
+            id,
+            location: bounds.position(),
+            size: bounds.size(),
+            end: None,
+        }
+    }
+
+    /// A line segment between two points in data coordinates.
+    pub fn segment(id: Id, from: Point, to: Point) -> Self {
+        Self {
+            id,
+            location: from,
+            size: Size::ZERO,
+            end: Some(to),
+        }
     }
+
+    /// The region covered by the item, with no size for points.
+    fn bounds(&self) -> Rectangle {
+        match self.end {
+            Some(end) => Rectangle::new(
+                Point::new(self.location.x.min(end.x), self.location.y.min(end.y)),
+                Size::new(
+                    (self.location.x - end.x).abs(),
+                    (self.location.y - end.y).abs(),
+                ),
+            ),
+            None => Rectangle::new(self.location, self.size),
+        }
+    }
+}
+
+/// Refers to an item of [`Items`], stays valid until the item is removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Key(usize);
+
+struct Stored<SeriesId, ItemId> {
+    item: (SeriesId, ItemId),
+    bounds: Rectangle,
+    /// The end of a segment, `None` for points and areas.
+    end: Option<Point>,
+    /// Location of the entry, `bounds` only keeps the bounding box of a
+    /// segment.
+    start: Point,
 }
 
-pub struct Items<SeriesId, ItemId>(BTreeMapFloat<BTreeMapFloat<(SeriesId, ItemId)>>);
+/// Items indexed by location in a uniform grid.
+///
+/// Items at identical locations are kept side by side. The cell size follows
+/// the extent and number of the items and is recomputed whenever the number
+/// of items has doubled since. Items spanning too many cells, like long
+/// segments, are kept in a separate list checked by every query.
+pub struct Items<SeriesId, ItemId> {
+    entries: Vec<Option<Stored<SeriesId, ItemId>>>,
+    /// Slots of removed entries, reused by the next insert.
+    free: Vec<usize>,
+    cells: HashMap<(i32, i32), Vec<usize>>,
+    large: Vec<usize>,
+    origin: Point,
+    cell: Size,
+    len: usize,
+    /// Number of items when the cell size was computed.
+    indexed: usize,
+}
 
 impl<SeriesId, ItemId> Items<SeriesId, ItemId>
 where
     SeriesId: Clone,
-    ItemId: Clone + std::fmt::Debug,
+    ItemId: Clone,
 {
+    /// The most cells an item may span before it counts as large.
+    const MAX_CELLS: i64 = 64;
+    /// Items per cell the cell size aims for.
+    const PER_CELL: f32 = 8.0;
+
     pub fn add_series(&mut self, id: SeriesId, series: &[Entry<ItemId>]) {
-        for entry in series.iter() {
-            let point = entry.location;
+        for entry in series {
+            self.push(id.clone(), entry);
+        }
 
-            self.0
-                .entry(OrderedFloat(point.x))
-                .or_default()
-                .insert(OrderedFloat(point.y), (id.clone(), entry.id.clone()));
+        if self.len > self.indexed * 2 {
+            self.rebuild();
         }
     }
 
-    pub fn collision(&self, rect: Rectangle) -> Vec<(SeriesId, ItemId)> {
-        let range = OrderedFloat(rect.x)..OrderedFloat(rect.x + rect.width);
+    /// Adds a single item.
+    pub fn insert(&mut self, series_id: SeriesId, entry: &Entry<ItemId>) -> Key {
+        let key = self.push(series_id, entry);
+
+        if self.len > self.indexed * 2 {
+            self.rebuild();
+        }
+
+        key
+    }
+
+    /// Removes an item, returning its id.
+    pub fn remove(&mut self, key: Key) -> Option<(SeriesId, ItemId)> {
+        let stored = self.entries.get_mut(key.0)?.take()?;
+
+        self.unindex(key.0, stored.bounds);
+        self.free.push(key.0);
+        self.len -= 1;
 
-        let mut items = vec![];
-        for (_, bucket) in self.0.range(range) {
-            let range = OrderedFloat(rect.y)..OrderedFloat(rect.y + rect.height);
+        Some(stored.item)
+    }
+
+    /// Moves an item to the location of `entry`, keeping its id.
+    pub fn update(&mut self, key: Key, entry: &Entry<ItemId>) {
+        let Some(bounds) = self
+            .entries
+            .get(key.0)
+            .and_then(Option::as_ref)
+            .map(|s| s.bounds)
+        else {
+            return;
+        };
 
-            let item_list = bucket
-                .range(range)
-                .map(|(_key, (series_id, item_id))| (series_id.clone(), item_id.clone()));
+        self.unindex(key.0, bounds);
 
-            items.extend(item_list);
+        if let Some(stored) = self.entries[key.0].as_mut() {
+            stored.bounds = entry.bounds();
+            stored.start = entry.location;
+            stored.end = entry.end;
         }
 
+        self.index(key.0);
+    }
+
+    /// Looks up the key of an item, by scanning all of them.
+    pub fn key(&self, item: &(SeriesId, ItemId)) -> Option<Key>
+    where
+        SeriesId: PartialEq,
+        ItemId: PartialEq,
+    {
+        self.stored()
+            .find(|(_, stored)| stored.item == *item)
+            .map(|(index, _)| Key(index))
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn collision(&self, rect: Rectangle) -> Vec<(SeriesId, ItemId)> {
+        self.locate(rect)
+            .into_iter()
+            .map(|(_, item)| item)
+            .collect()
+    }
+
+    /// Like [`Self::collision`], together with the bounds of each item, which
+    /// have no size for points. Segments are left out, see
+    /// [`Self::segments`].
+    pub fn locate(&self, rect: Rectangle) -> Vec<(Rectangle, (SeriesId, ItemId))> {
+        self.query(rect)
+            .filter(|stored| stored.end.is_none())
+            .map(|stored| (stored.bounds, stored.item.clone()))
+            .collect()
+    }
+
+    /// The segments whose bounding box intersects `rect`.
+    pub fn segments(&self, rect: Rectangle) -> Vec<([Point; 2], (SeriesId, ItemId))> {
+        self.query(rect)
+            .filter_map(|stored| Some(([stored.start, stored.end?], stored.item.clone())))
+            .collect()
+    }
+
+    /// Where an item is located, the lower left corner for areas and the start
+    /// for segments.
+    pub fn location(&self, item: &(SeriesId, ItemId)) -> Option<iced::Point>
+    where
+        SeriesId: PartialEq,
+        ItemId: PartialEq,
+    {
+        self.stored()
+            .find(|(_, stored)| stored.item == *item)
+            .map(|(_, stored)| stored.start)
+    }
+
+    /// All items but segments ordered by x, then y, areas by their center,
+    /// together with the point they are ordered by.
+    pub fn ordered(&self) -> Vec<(Point, (SeriesId, ItemId))> {
+        let mut items: Vec<_> = self
+            .stored()
+            .filter(|(_, stored)| stored.end.is_none())
+            .map(|(_, stored)| {
+                let point = if stored.bounds.size() == Size::ZERO {
+                    stored.start
+                } else {
+                    stored.bounds.center()
+                };
+
+                (point, &stored.item)
+            })
+            .collect();
+        items.sort_by_key(|(point, _)| (OrderedFloat(point.x), OrderedFloat(point.y)));
+
         items
+            .into_iter()
+            .map(|(point, item)| (point, item.clone()))
+            .collect()
+    }
+
+    fn stored(&self) -> impl Iterator<Item = (usize, &Stored<SeriesId, ItemId>)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(index, stored)| Some((index, stored.as_ref()?)))
+    }
+
+    /// The items whose bounds intersect `rect`, edges included, each once.
+    fn query(&self, rect: Rectangle) -> impl Iterator<Item = &Stored<SeriesId, ItemId>> {
+        let ((x_min, y_min), (x_max, y_max)) = self.cell_range(rect);
+        let count = cell_count((x_min, y_min), (x_max, y_max));
+
+        let mut indices: Vec<usize> = if count > self.cells.len() as i64 {
+            // cheaper to go through the occupied cells only
+            self.cells
+                .iter()
+                .filter(|((x, y), _)| (x_min..=x_max).contains(x) && (y_min..=y_max).contains(y))
+                .flat_map(|(_, cell)| cell.iter().copied())
+                .collect()
+        } else {
+            (x_min..=x_max)
+                .flat_map(|x| (y_min..=y_max).map(move |y| (x, y)))
+                .filter_map(|cell| self.cells.get(&cell))
+                .flat_map(|cell| cell.iter().copied())
+                .collect()
+        };
+        indices.extend(self.large.iter().copied());
+
+        // areas and segments may be found in several cells
+        indices.sort_unstable();
+        indices.dedup();
+
+        indices
+            .into_iter()
+            .filter_map(|index| self.entries[index].as_ref())
+            .filter(move |stored| overlaps(&stored.bounds, &rect))
+    }
+
+    fn push(&mut self, series_id: SeriesId, entry: &Entry<ItemId>) -> Key {
+        let stored = Stored {
+            item: (series_id, entry.id.clone()),
+            bounds: entry.bounds(),
+            end: entry.end,
+            start: entry.location,
+        };
+
+        let index = match self.free.pop() {
+            Some(index) => {
+                self.entries[index] = Some(stored);
+                index
+            }
+            None => {
+                self.entries.push(Some(stored));
+                self.entries.len() - 1
+            }
+        };
+        self.len += 1;
+
+        if self.indexed > 0 {
+            self.index(index);
+        }
+
+        Key(index)
+    }
+
+    /// Recomputes the cell size for a few items per cell and indexes all items
+    /// again.
+    fn rebuild(&mut self) {
+        let bounds = self
+            .stored()
+            .map(|(_, stored)| stored.bounds)
+            .reduce(|a, b| a.union(&b))
+            .unwrap_or_default();
+
+        let cells = (self.len as f32 / Self::PER_CELL).sqrt().ceil().max(1.0);
+        let size = |length: f32| {
+            if length > 0.0 && length.is_finite() {
+                length / cells
+            } else {
+                1.0
+            }
+        };
+
+        self.origin = bounds.position();
+        self.cell = Size::new(size(bounds.width), size(bounds.height));
+        self.indexed = self.len;
+
+        self.cells.clear();
+        self.large.clear();
+        for index in 0..self.entries.len() {
+            if self.entries[index].is_some() {
+                self.index(index);
+            }
+        }
+    }
+
+    fn index(&mut self, index: usize) {
+        let Some(stored) = &self.entries[index] else {
+            return;
+        };
+
+        let ((x_min, y_min), (x_max, y_max)) = self.cell_range(stored.bounds);
+        let count = cell_count((x_min, y_min), (x_max, y_max));
+
+        if count > Self::MAX_CELLS {
+            self.large.push(index);
+            return;
+        }
+
+        for x in x_min..=x_max {
+            for y in y_min..=y_max {
+                self.cells.entry((x, y)).or_default().push(index);
+            }
+        }
+    }
+
+    fn unindex(&mut self, index: usize, bounds: Rectangle) {
+        if let Some(position) = self.large.iter().position(|i| *i == index) {
+            self.large.swap_remove(position);
+            return;
+        }
+
+        let ((x_min, y_min), (x_max, y_max)) = self.cell_range(bounds);
+        for x in x_min..=x_max {
+            for y in y_min..=y_max {
+                let Some(cell) = self.cells.get_mut(&(x, y)) else {
+                    continue;
+                };
+
+                if let Some(position) = cell.iter().position(|i| *i == index) {
+                    cell.swap_remove(position);
+                }
+                if cell.is_empty() {
+                    self.cells.remove(&(x, y));
+                }
+            }
+        }
+    }
+
+    /// The first and last cell covered by the rectangle.
+    fn cell_range(&self, rect: Rectangle) -> ((i32, i32), (i32, i32)) {
+        let cell = |point: Point| {
+            let offset = point - self.origin;
+            let cell = Vector::new(offset.x / self.cell.width, offset.y / self.cell.height);
+
+            // saturating casts keep huge or infinite rectangles in range
+            (cell.x.floor() as i32, cell.y.floor() as i32)
+        };
+
+        (
+            cell(rect.position()),
+            cell(rect.position() + Vector::new(rect.width, rect.height)),
+        )
     }
 }
 
 impl<SeriesId, ItemId> Default for Items<SeriesId, ItemId> {
     fn default() -> Self {
-        Self(BTreeMapFloat::new())
+        Self {
+            entries: Vec::new(),
+            free: Vec::new(),
+            cells: HashMap::new(),
+            large: Vec::new(),
+            origin: Point::ORIGIN,
+            cell: Size::new(1.0, 1.0),
+            len: 0,
+            indexed: 0,
+        }
+    }
+}
+
+/// Number of cells between the first and last cell, saturating for the huge
+/// spans of tiny cells.
+fn cell_count((x_min, y_min): (i32, i32), (x_max, y_max): (i32, i32)) -> i64 {
+    let span = |min: i32, max: i32| i64::from(max) - i64::from(min) + 1;
+
+    span(x_min, x_max).saturating_mul(span(y_min, y_max))
+}
+
+/// Whether two rectangles intersect, touching edges included.
+fn overlaps(a: &Rectangle, b: &Rectangle) -> bool {
+    a.x <= b.x + b.width && b.x <= a.x + a.width && a.y <= b.y + b.height && b.y <= a.y + a.height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted(mut items: Vec<(u8, usize)>) -> Vec<(u8, usize)> {
+        items.sort_unstable();
+        items
+    }
+
+    fn around(x: f32, y: f32, radius: f32) -> Rectangle {
+        Rectangle::new(
+            Point::new(x - radius, y - radius),
+            Size::new(radius * 2.0, radius * 2.0),
+        )
+    }
+
+    /// Points on a 10 by 10 grid, enough to index them in several cells.
+    fn grid() -> Items<u8, usize> {
+        let entries: Vec<_> = (0..100)
+            .map(|i| Entry::new(i, Point::new((i % 10) as f32, (i / 10) as f32)))
+            .collect();
+
+        let mut items = Items::default();
+        items.add_series(0, &entries);
+        items
+    }
+
+    #[test]
+    fn items_at_the_same_location() {
+        let mut items = grid();
+        items.add_series(1, &[Entry::new(0, Point::new(4.0, 4.0))]);
+
+        assert_eq!(
+            sorted(items.collision(around(4.0, 4.0, 0.1))),
+            [(0, 44), (1, 0)]
+        );
+    }
+
+    #[test]
+    fn queries_include_edges() {
+        let items = grid();
+        let rect = Rectangle::new(Point::new(2.0, 3.0), Size::new(1.0, 1.0));
+
+        assert_eq!(
+            sorted(items.collision(rect)),
+            [(0, 32), (0, 33), (0, 42), (0, 43)]
+        );
+    }
+
+    #[test]
+    fn remove_reuses_slot() {
+        let mut items = Items::default();
+        let first = items.insert(0, &Entry::new(0, Point::new(1.0, 1.0)));
+        items.insert(0, &Entry::new(1, Point::new(2.0, 2.0)));
+
+        assert_eq!(items.remove(first), Some((0, 0)));
+        assert_eq!(items.remove(first), None);
+        assert_eq!(items.len(), 1);
+        assert!(items.collision(around(1.0, 1.0, 0.1)).is_empty());
+
+        let reused = items.insert(0, &Entry::new(2, Point::new(3.0, 3.0)));
+        assert_eq!(reused, first);
+        assert_eq!(items.collision(around(3.0, 3.0, 0.1)), [(0, 2)]);
+    }
+
+    #[test]
+    fn update_moves_across_cells() {
+        let mut items = grid();
+        let key = items.key(&(0, 0)).unwrap();
+
+        items.update(key, &Entry::new(0, Point::new(8.5, 8.5)));
+
+        assert!(items.collision(around(0.0, 0.0, 0.1)).is_empty());
+        assert_eq!(items.collision(around(8.5, 8.5, 0.1)), [(0, 0)]);
+        assert_eq!(items.len(), 100);
+    }
+
+    #[test]
+    fn long_segments_are_large() {
+        let mut items = grid();
+        let key = items.insert(
+            1,
+            &Entry::segment(0, Point::new(0.0, 0.0), Point::new(90.0, 90.0)),
+        );
+
+        assert_eq!(items.large, [key.0]);
+        assert_eq!(
+            items
+                .segments(around(4.5, 4.5, 0.1))
+                .into_iter()
+                .map(|(_, item)| item)
+                .collect::<Vec<_>>(),
+            [(1, 0)]
+        );
+
+        items.remove(key);
+        assert!(items.large.is_empty());
+    }
+
+    #[test]
+    fn tiny_cells_and_huge_queries() {
+        let entries: Vec<_> = (0..100)
+            .map(|i| Entry::new(i, Point::new(i as f32 * 1e-30, i as f32 * 1e-30)))
+            .collect();
+
+        let mut items = Items::default();
+        items.add_series(0, &entries);
+        let key = items.insert(
+            1,
+            &Entry::area(0, Rectangle::new(Point::ORIGIN, Size::new(1e30, 1e30))),
+        );
+
+        assert_eq!(items.large, [key.0]);
+
+        let huge = Rectangle::new(Point::new(-1e30, -1e30), Size::new(2e30, 2e30));
+        assert_eq!(items.collision(huge).len(), 101);
+        assert_eq!(
+            items
+                .collision(Rectangle::new(Point::ORIGIN, Size::INFINITY))
+                .len(),
+            101
+        );
+    }
+
+    #[test]
+    fn query_after_rebuild() {
+        let mut items = Items::default();
+        items.add_series(0, &[Entry::new(0, Point::new(0.0, 0.0))]);
+        let cell = items.cell;
+
+        for i in 1..100 {
+            items.insert(0, &Entry::new(i, Point::new(i as f32, i as f32)));
+        }
+
+        assert_ne!(items.cell, cell);
+        assert_eq!(items.collision(around(0.0, 0.0, 0.1)), [(0, 0)]);
+        assert_eq!(items.collision(around(99.0, 99.0, 0.1)), [(0, 99)]);
+        assert_eq!(
+            items
+                .collision(Rectangle::new(Point::ORIGIN, Size::new(99.0, 99.0)))
+                .len(),
+            100
+        );
     }
 }
@@ -0,0 +1,50 @@
+use std::cell::RefCell;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+/// A handle shared by several [`Chart`](super::Chart)s, synchronizing their
+/// cursor and x range.
+///
+/// Keep it in your application state and pass it to every chart which should
+/// follow the others, similar to a [`canvas::Cache`](iced::widget::canvas::Cache).
+/// Hovering a linked chart shows a crosshair at the same x in all of them and
+/// dragging one pans all of them, without any messages going through the
+/// application.
+#[derive(Debug, Clone, Default)]
+pub struct Link(Rc<RefCell<Shared>>);
+
+#[derive(Debug, Default)]
+struct Shared {
+    cursor_x: Option<f32>,
+    x_range: Option<RangeInclusive<f32>>,
+}
+
+impl Link {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The x coordinate hovered in any of the linked charts.
+    pub fn cursor_x(&self) -> Option<f32> {
+        self.0.borrow().cursor_x
+    }
+
+    /// The x range all linked charts use, overriding their own range.
+    pub fn x_range(&self) -> Option<RangeInclusive<f32>> {
+        self.0.borrow().x_range.clone()
+    }
+
+    /// Sets the shared x range, `None` lets every chart use its own range again.
+    pub fn set_x_range(&self, range: Option<RangeInclusive<f32>>) {
+        self.0.borrow_mut().x_range = range;
+    }
+
+    /// Returns whether the value changed.
+    pub(super) fn set_cursor_x(&self, x: Option<f32>) -> bool {
+        let mut shared = self.0.borrow_mut();
+        let changed = shared.cursor_x != x;
+        shared.cursor_x = x;
+
+        changed
+    }
+}
@@ -0,0 +1,556 @@
+use core::f32;
+
+use iced::advanced::Renderer as _;
+use iced::advanced::graphics::geometry::Renderer as _;
+use iced::advanced::widget::{Tree, tree};
+use iced::advanced::{Clipboard, Layout, Shell, Widget, layout, mouse, renderer};
+use iced::widget::canvas::{self, Path, Stroke, path::Arc};
+use iced::widget::text::Shaping;
+use iced::{Color, Element, Length, Point, Radians, Rectangle, Size, mouse::Cursor};
+use iced::{Font, Renderer, Vector, alignment, touch};
+
+use super::style::{self, Catalog, Style, TABLEAU10};
+
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+type StateFn<'a, Message> = Box<dyn Fn(&State) -> Message + 'a>;
+
+/// A proportional chart, drawing each [`Slice`] as a sector of a circle.
+pub struct PieChart<'a, Message, Theme = iced::Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    width: Length,
+    height: Length,
+    shaping: Shaping,
+
+    slices: Vec<Slice>,
+
+    inner_radius: f32,
+    padding: f32,
+    explode: f32,
+    labels: Labels,
+
+    cache: canvas::Cache,
+
+    on_move: Option<StateFn<'a, Message>>,
+    on_press: Option<StateFn<'a, Message>>,
+    on_release: Option<StateFn<'a, Message>>,
+    class: Theme::Class<'a>,
+}
+
+pub struct Slice {
+    pub value: f32,
+    pub label: Option<String>,
+    pub color: Option<Color>,
+}
+
+impl Slice {
+    pub fn new(value: f32) -> Self {
+        Self {
+            value,
+            label: None,
+            color: None,
+        }
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl From<f32> for Slice {
+    fn from(value: f32) -> Self {
+        Slice::new(value)
+    }
+}
+
+/// Where slice labels are placed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelPosition {
+    #[default]
+    Inside,
+    /// Outside of the circle, connected to the slice by a leader line.
+    Outside,
+    Hidden,
+}
+
+#[derive(Default)]
+pub struct Labels {
+    pub position: LabelPosition,
+    pub color: Option<Color>,
+    pub font_size: Option<iced::Pixels>,
+}
+
+impl Labels {
+    pub fn position(mut self, position: LabelPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    pub fn font_size(mut self, font_size: impl Into<iced::Pixels>) -> Self {
+        self.font_size = Some(font_size.into());
+        self
+    }
+}
+
+impl<'a, Message, Theme> PieChart<'a, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    const LEADER_LENGTH: f32 = 12.0;
+
+    pub fn new() -> Self {
+        Self {
+            width: Length::Fill,
+            height: Length::Fill,
+            shaping: Shaping::default(),
+            slices: Vec::new(),
+            inner_radius: 0.0,
+            padding: 10.0,
+            explode: 8.0,
+            labels: Labels::default(),
+            cache: canvas::Cache::new(),
+            on_move: None,
+            on_press: None,
+            on_release: None,
+            class: Theme::default(),
+        }
+    }
+
+    /// set width
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// set height
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// set text shaping
+    pub fn text_shaping(mut self, shaping: Shaping) -> Self {
+        self.shaping = shaping;
+        self
+    }
+
+    /// Radius of the hole as a fraction of the outer radius, turning the pie
+    /// into a donut.
+    pub fn inner_radius(mut self, ratio: f32) -> Self {
+        self.inner_radius = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Space between the circle and the bounds of the widget.
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// Distance a hovered slice is moved out of the circle.
+    pub fn explode(mut self, explode: f32) -> Self {
+        self.explode = explode;
+        self
+    }
+
+    /// Sets the style of the [`PieChart`]. Slices without a color take theirs
+    /// from the style palette.
+    pub fn style(mut self, style: impl Fn(&Theme) -> Style + 'a) -> Self
+    where
+        Theme::Class<'a>: From<style::StyleFn<'a, Theme>>,
+    {
+        self.class = (Box::new(style) as style::StyleFn<'a, Theme>).into();
+        self
+    }
+
+    /// Sets the style class of the [`PieChart`].
+    pub fn class(mut self, class: impl Into<Theme::Class<'a>>) -> Self {
+        self.class = class.into();
+        self
+    }
+
+    pub fn labels(mut self, labels: Labels) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    pub fn push_slice(mut self, slice: impl Into<Slice>) -> Self {
+        self.slices.push(slice.into());
+        self
+    }
+
+    pub fn extend_slices(self, slices: impl IntoIterator<Item = impl Into<Slice>>) -> Self {
+        slices.into_iter().fold(self, Self::push_slice)
+    }
+
+    pub fn on_press(mut self, msg: impl Fn(&State) -> Message + 'a) -> Self {
+        self.on_press = Some(Box::new(msg));
+        self
+    }
+
+    pub fn on_release(mut self, msg: impl Fn(&State) -> Message + 'a) -> Self {
+        self.on_release = Some(Box::new(msg));
+        self
+    }
+
+    pub fn on_move(mut self, msg: impl Fn(&State) -> Message + 'a) -> Self {
+        self.on_move = Some(Box::new(msg));
+        self
+    }
+
+    fn radius(&self, bounds: Size) -> f32 {
+        let mut radius = bounds.width.min(bounds.height) / 2.0 - self.padding - self.explode;
+        if self.labels.position == LabelPosition::Outside {
+            radius -= 2.0 * Self::LEADER_LENGTH + self.font_size().0;
+        }
+
+        radius.max(0.0)
+    }
+
+    fn font_size(&self) -> iced::Pixels {
+        self.labels.font_size.unwrap_or(12.into())
+    }
+
+    /// Start and end angle of every slice, clockwise from the top.
+    fn angles(&self) -> Vec<(f32, f32)> {
+        let total: f32 = self.slices.iter().map(|s| s.value.max(0.0)).sum();
+        if total <= 0.0 {
+            return vec![];
+        }
+
+        let mut start = -FRAC_PI_2;
+        self.slices
+            .iter()
+            .map(|slice| {
+                let end = start + slice.value.max(0.0) / total * TAU;
+                let angles = (start, end);
+                start = end;
+
+                angles
+            })
+            .collect()
+    }
+
+    fn hit_test(&self, bounds: Size, position: Point) -> Option<usize> {
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let radius = self.radius(bounds);
+        let offset = position - center;
+        let distance = offset.x.hypot(offset.y);
+
+        if distance < radius * self.inner_radius || distance > radius + self.explode {
+            return None;
+        }
+
+        let mut angle = offset.y.atan2(offset.x);
+        if angle < -FRAC_PI_2 {
+            angle += TAU;
+        }
+
+        self.angles()
+            .iter()
+            .position(|(start, end)| angle >= *start && angle < *end)
+    }
+
+    fn draw_slices(&self, frame: &mut canvas::Frame, hovered: Option<usize>, style: &Style) {
+        let bounds = frame.size();
+        let center = Point::new(bounds.width / 2.0, bounds.height / 2.0);
+        let radius = self.radius(bounds);
+        let inner_radius = radius * self.inner_radius;
+        let font_size = self.font_size();
+        let label_color = self.labels.color.unwrap_or(style.label);
+        let palette: &[Color] = if style.palette.is_empty() {
+            &TABLEAU10
+        } else {
+            &style.palette
+        };
+
+        for (index, ((start, end), slice)) in
+            self.angles().into_iter().zip(&self.slices).enumerate()
+        {
+            let mid = (start + end) / 2.0;
+            let direction = Vector::new(mid.cos(), mid.sin());
+
+            let center = if hovered == Some(index) {
+                center + direction * self.explode
+            } else {
+                center
+            };
+
+            let path = Path::new(|b| {
+                b.move_to(center + Vector::new(start.cos(), start.sin()) * inner_radius);
+                b.arc(Arc {
+                    center,
+                    radius,
+                    start_angle: Radians(start),
+                    end_angle: Radians(end),
+                });
+                if inner_radius > 0.0 {
+                    b.line_to(center + Vector::new(end.cos(), end.sin()) * inner_radius);
+                    b.arc(Arc {
+                        center,
+                        radius: inner_radius,
+                        start_angle: Radians(end),
+                        end_angle: Radians(start),
+                    });
+                } else {
+                    b.line_to(center);
+                }
+                b.close();
+            });
+
+            let color = slice.color.unwrap_or(palette[index % palette.len()]);
+            frame.fill(
+                &path,
+                canvas::Fill {
+                    style: canvas::Style::Solid(color),
+                    ..Default::default()
+                },
+            );
+
+            let Some(label) = &slice.label else {
+                continue;
+            };
+
+            match self.labels.position {
+                LabelPosition::Hidden => {}
+                LabelPosition::Inside => {
+                    let position = center + direction * ((radius + inner_radius) / 2.0);
+
+                    frame.fill_text(canvas::Text {
+                        content: label.clone(),
+                        size: font_size,
+                        position,
+                        color: label_color,
+                        align_x: alignment::Horizontal::Center,
+                        align_y: alignment::Vertical::Center,
+                        font: Font::MONOSPACE,
+                        shaping: self.shaping,
+                        ..canvas::Text::default()
+                    });
+                }
+                LabelPosition::Outside => {
+                    let edge = center + direction * radius;
+                    let elbow = center + direction * (radius + Self::LEADER_LENGTH);
+                    let side = if direction.x < 0.0 { -1.0 } else { 1.0 };
+                    let end = elbow + Vector::new(side * Self::LEADER_LENGTH, 0.0);
+
+                    let leader = Path::new(|b| {
+                        b.move_to(edge);
+                        b.line_to(elbow);
+                        b.line_to(end);
+                    });
+                    frame.stroke(
+                        &leader,
+                        Stroke::default().with_width(1.0).with_color(label_color),
+                    );
+
+                    let (align_x, offset) = if side < 0.0 {
+                        (alignment::Horizontal::Right, -4.0)
+                    } else {
+                        (alignment::Horizontal::Left, 4.0)
+                    };
+
+                    frame.fill_text(canvas::Text {
+                        content: label.clone(),
+                        size: font_size,
+                        position: end + Vector::new(offset, 0.0),
+                        color: label_color,
+                        align_x,
+                        align_y: alignment::Vertical::Center,
+                        font: Font::MONOSPACE,
+                        shaping: self.shaping,
+                        ..canvas::Text::default()
+                    });
+                }
+            }
+        }
+    }
+}
+
+impl<Message, Theme> Widget<Message, Theme, Renderer> for PieChart<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    fn size(&self) -> Size<Length> {
+        Size::new(self.width, self.height)
+    }
+
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn children(&self) -> Vec<Tree> {
+        vec![]
+    }
+
+    #[inline]
+    fn layout(
+        &self,
+        _tree: &mut Tree,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        layout::atomic(limits, self.width, self.height)
+    }
+
+    #[inline]
+    fn draw(
+        &self,
+        tree: &Tree,
+        renderer: &mut Renderer,
+        theme: &Theme,
+        _defaults: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        if bounds.width < 1.0 || bounds.height < 1.0 {
+            return;
+        }
+
+        let state: &State = tree.state.downcast_ref();
+        let style = theme.style(&self.class);
+
+        let geometry = self.cache.draw(renderer, bounds.size(), |frame| {
+            self.draw_slices(frame, state.hovered, &style);
+        });
+
+        renderer.with_translation(Vector::new(bounds.x, bounds.y), |renderer| {
+            renderer.draw_geometry(geometry)
+        });
+    }
+
+    fn update(
+        &mut self,
+        tree: &mut Tree,
+        event: &iced::Event,
+        layout: Layout<'_>,
+        cursor: mouse::Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let state: &mut State = tree.state.downcast_mut();
+
+        let hovered = cursor
+            .position_in(bounds)
+            .and_then(|position| self.hit_test(bounds.size(), position));
+
+        if hovered != state.hovered {
+            state.hovered = hovered;
+            state.item_list = hovered.map(|index| vec![index]);
+            self.cache.clear();
+            shell.request_redraw();
+        }
+
+        if cursor.position_in(bounds).is_none() {
+            return;
+        }
+
+        if let Some(message) = self.on_press.as_ref() {
+            if let iced::Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerPressed { .. }) = event
+            {
+                shell.publish(message(state));
+
+                return;
+            }
+        }
+
+        if let Some(message) = self.on_release.as_ref() {
+            if let iced::Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | iced::Event::Touch(touch::Event::FingerLifted { .. }) = event
+            {
+                shell.publish(message(state));
+
+                return;
+            }
+        }
+
+        if let Some(message) = self.on_move.as_ref() {
+            if let iced::Event::Mouse(mouse::Event::CursorMoved { .. })
+            | iced::Event::Touch(touch::Event::FingerMoved { .. }) = event
+            {
+                shell.publish(message(state));
+            }
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        tree: &Tree,
+        _layout: Layout<'_>,
+        _cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let state: &State = tree.state.downcast_ref();
+
+        if state.hovered.is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+impl<Message, Theme> Default for PieChart<'_, Message, Theme>
+where
+    Message: Clone,
+    Theme: Catalog,
+{
+    fn default() -> Self {
+        PieChart::new()
+    }
+}
+
+/// Local state of the [`PieChart`].
+#[derive(Default)]
+pub struct State {
+    hovered: Option<usize>,
+    item_list: Option<Vec<usize>>,
+}
+
+impl State {
+    /// Index of the hovered slice.
+    pub fn hovered(&self) -> Option<usize> {
+        self.hovered
+    }
+
+    pub fn items(&self) -> Option<&Vec<usize>> {
+        self.item_list.as_ref()
+    }
+}
+
+impl<'a, Message, Theme> From<PieChart<'a, Message, Theme>> for Element<'a, Message, Theme>
+where
+    Message: 'a + Clone,
+    Theme: 'a + Catalog,
+{
+    fn from(chart: PieChart<'a, Message, Theme>) -> Element<'a, Message, Theme, Renderer> {
+        Element::new(chart)
+    }
+}
@@ -0,0 +1,111 @@
+use std::f32::consts::{FRAC_PI_2, TAU};
+use std::ops::RangeInclusive;
+
+use iced::{Point, Rectangle, Size};
+
+use super::CoordinateSystem;
+
+/// A polar plane, mapping data `x` to the angle and data `y` to the radius.
+///
+/// The angle range covers one full turn, clockwise starting at the top.
+pub struct Plane {
+    pub center: Point,
+    /// Radius of the outermost ring in pixels.
+    pub radius: f32,
+    pub angle: Axis,
+    pub distance: Axis,
+}
+
+pub struct Axis {
+    pub min: f32,
+    pub length: f32,
+}
+
+impl Axis {
+    pub fn new(range: &RangeInclusive<f32>) -> Self {
+        let min = *range.start();
+        let max = *range.end();
+
+        Self {
+            min,
+            length: max - min,
+        }
+    }
+
+    /// Position of the value between `min` and `max`, as a fraction.
+    fn fraction(&self, value: f32) -> f32 {
+        if self.length == 0.0 {
+            0.0
+        } else {
+            (value - self.min) / self.length
+        }
+    }
+}
+
+impl Plane {
+    pub fn new(
+        angle: &RangeInclusive<f32>,
+        distance: &RangeInclusive<f32>,
+        center: Point,
+        radius: f32,
+    ) -> Self {
+        Self {
+            center,
+            radius,
+            angle: Axis::new(angle),
+            distance: Axis::new(distance),
+        }
+    }
+
+    /// Screen angle in radians, clockwise from the positive x-axis.
+    pub fn scale_to_angle(&self, value: f32) -> f32 {
+        self.angle.fraction(value) * TAU - FRAC_PI_2
+    }
+
+    /// Distance from the center in pixels.
+    pub fn scale_to_radius(&self, value: f32) -> f32 {
+        self.distance.fraction(value) * self.radius
+    }
+
+    pub fn scale_to_polar(&self, point: Point) -> Point {
+        let angle = self.scale_to_angle(point.x);
+        let radius = self.scale_to_radius(point.y);
+
+        Point::new(
+            self.center.x + radius * angle.cos(),
+            self.center.y + radius * angle.sin(),
+        )
+    }
+
+    pub fn get_polar(&self, pos: Point) -> Point {
+        let offset = pos - self.center;
+
+        let mut turn = (offset.y.atan2(offset.x) + FRAC_PI_2) / TAU;
+        if turn < 0.0 {
+            turn += 1.0;
+        }
+        let distance = offset.x.hypot(offset.y) / self.radius;
+
+        Point::new(
+            self.angle.min + turn * self.angle.length,
+            self.distance.min + distance * self.distance.length,
+        )
+    }
+}
+
+impl CoordinateSystem for Plane {
+    fn to_screen(&self, point: Point) -> Point {
+        self.scale_to_polar(point)
+    }
+
+    fn to_data(&self, position: Point) -> Point {
+        self.get_polar(position)
+    }
+
+    fn bounds(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new(self.angle.min, self.distance.min),
+            Size::new(self.angle.length, self.distance.length),
+        )
+    }
+}
@@ -0,0 +1,216 @@
+use iced::Size;
+use iced::widget::canvas::path::lyon_path::Event;
+use iced::widget::canvas::{self, Fill, LineCap, LineJoin, Path, Stroke, fill};
+use tiny_skia::{Paint, Pixmap, Transform};
+
+use super::Target;
+use super::target::solid_color;
+
+/// A chart rendered on the CPU, see [`crate::chart::Chart::to_image`].
+///
+/// Text is drawn as glyph outlines, so the result depends on the fonts
+/// installed on the system.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pixmap: Pixmap,
+    scale_factor: f32,
+}
+
+impl Image {
+    /// A transparent image of the given logical size.
+    ///
+    /// Fails if the scale factor isn't positive and finite, or the physical
+    /// size isn't finite or too large to allocate.
+    pub fn new(size: Size, scale_factor: f32) -> Result<Self, InvalidSize> {
+        if !scale_factor.is_finite() || scale_factor <= 0.0 {
+            return Err(InvalidSize(size));
+        }
+
+        let (width, height) = (size.width * scale_factor, size.height * scale_factor);
+        if !width.is_finite() || !height.is_finite() {
+            return Err(InvalidSize(size));
+        }
+
+        let pixmap = Pixmap::new(width.ceil().max(1.0) as u32, height.ceil().max(1.0) as u32)
+            .ok_or(InvalidSize(size))?;
+
+        Ok(Self {
+            pixmap,
+            scale_factor,
+        })
+    }
+
+    /// Width in physical pixels.
+    pub fn width(&self) -> u32 {
+        self.pixmap.width()
+    }
+
+    /// Height in physical pixels.
+    pub fn height(&self) -> u32 {
+        self.pixmap.height()
+    }
+
+    /// The pixels as RGBA8 row by row, with straight (not premultiplied)
+    /// alpha.
+    pub fn rgba(&self) -> Vec<u8> {
+        self.pixmap
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let color = pixel.demultiply();
+                [color.red(), color.green(), color.blue(), color.alpha()]
+            })
+            .collect()
+    }
+
+    /// Encodes the image as PNG.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        self.pixmap.encode_png()
+    }
+
+    fn transform(&self) -> Transform {
+        Transform::from_scale(self.scale_factor, self.scale_factor)
+    }
+}
+
+/// The size of an [`Image`] can't be allocated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidSize(pub Size);
+
+impl std::fmt::Display for InvalidSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid image size {}x{}", self.0.width, self.0.height)
+    }
+}
+
+impl std::error::Error for InvalidSize {}
+
+impl Target for Image {
+    fn size(&self) -> Size {
+        Size::new(
+            self.pixmap.width() as f32 / self.scale_factor,
+            self.pixmap.height() as f32 / self.scale_factor,
+        )
+    }
+
+    fn fill(&mut self, path: &Path, fill: Fill) {
+        let Some(path) = convert(path) else {
+            return;
+        };
+
+        let rule = match fill.rule {
+            fill::Rule::NonZero => tiny_skia::FillRule::Winding,
+            fill::Rule::EvenOdd => tiny_skia::FillRule::EvenOdd,
+        };
+
+        let transform = self.transform();
+        self.pixmap
+            .fill_path(&path, &paint(fill.style), rule, transform, None);
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>) {
+        if stroke.width <= 0.0 {
+            return;
+        }
+        let Some(path) = convert(path) else {
+            return;
+        };
+
+        let dash = if stroke.line_dash.segments.is_empty() {
+            None
+        } else {
+            tiny_skia::StrokeDash::new(
+                stroke.line_dash.segments.to_vec(),
+                stroke.line_dash.offset as f32,
+            )
+        };
+
+        let style = tiny_skia::Stroke {
+            width: stroke.width,
+            line_cap: match stroke.line_cap {
+                LineCap::Butt => tiny_skia::LineCap::Butt,
+                LineCap::Square => tiny_skia::LineCap::Square,
+                LineCap::Round => tiny_skia::LineCap::Round,
+            },
+            line_join: match stroke.line_join {
+                LineJoin::Miter => tiny_skia::LineJoin::Miter,
+                LineJoin::Round => tiny_skia::LineJoin::Round,
+                LineJoin::Bevel => tiny_skia::LineJoin::Bevel,
+            },
+            dash,
+            ..tiny_skia::Stroke::default()
+        };
+
+        let transform = self.transform();
+        self.pixmap
+            .stroke_path(&path, &paint(stroke.style), &style, transform, None);
+    }
+
+    fn fill_text(&mut self, text: canvas::Text) {
+        text.draw_with(|path, color| {
+            self.fill(&path, color.into());
+        });
+    }
+}
+
+fn convert(path: &Path) -> Option<tiny_skia::Path> {
+    let mut builder = tiny_skia::PathBuilder::new();
+
+    for event in path.raw().iter() {
+        match event {
+            Event::Begin { at } => builder.move_to(at.x, at.y),
+            Event::Line { to, .. } => builder.line_to(to.x, to.y),
+            Event::Quadratic { ctrl, to, .. } => builder.quad_to(ctrl.x, ctrl.y, to.x, to.y),
+            Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => builder.cubic_to(ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y),
+            Event::End { close: true, .. } => builder.close(),
+            Event::End { close: false, .. } => {}
+        }
+    }
+
+    builder.finish()
+}
+
+fn paint(style: canvas::Style) -> Paint<'static> {
+    let [r, g, b, a] = solid_color(style).into_rgba8();
+
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, a);
+    paint.anti_alias = true;
+
+    paint
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physical_size() {
+        let image = Image::new(Size::new(10.0, 5.5), 2.0).unwrap();
+
+        assert_eq!((image.width(), image.height()), (20, 11));
+        assert_eq!(image.size(), Size::new(10.0, 5.5));
+    }
+
+    #[test]
+    fn rejects_invalid_scale_factor() {
+        let size = Size::new(10.0, 10.0);
+
+        for scale_factor in [0.0, -1.0, f32::NAN, f32::INFINITY] {
+            assert_eq!(
+                Image::new(size, scale_factor),
+                Err(InvalidSize(size)),
+                "scale factor {scale_factor}"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_size() {
+        assert!(Image::new(Size::new(f32::INFINITY, 1.0), 1.0).is_err());
+        assert!(Image::new(Size::new(1.0e9, 1.0e9), 1.0).is_err());
+    }
+}
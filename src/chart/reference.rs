@@ -0,0 +1,260 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Font, Pixels, Point, Vector, alignment,
+    widget::canvas::{self, Path, Stroke},
+};
+
+use super::annotation::Layer;
+use super::{CoordinateSystem, Target, polyline};
+
+/// Number of points used for each edge, so lines bend along polar charts.
+const SAMPLES: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    Horizontal(f32),
+    Vertical(f32),
+    HorizontalSpan(RangeInclusive<f32>),
+    VerticalSpan(RangeInclusive<f32>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// Defaults to the axis color of the chart style.
+    pub color: Option<Color>,
+    pub width: f32,
+    /// Opacity of the area covered by a span.
+    pub opacity: f32,
+    pub font_size: Pixels,
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self {
+            color: None,
+            width: 1.0,
+            opacity: 0.2,
+            font_size: 12.into(),
+        }
+    }
+}
+
+/// A line or shaded span at a fixed value, spanning the whole visible plot.
+///
+/// References are ignored while computing the ranges of a chart, unless
+/// [`Reference::include_in_range`] is set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub kind: Kind,
+    pub style: Style,
+    pub label: Option<String>,
+    pub layer: Layer,
+    include_in_range: bool,
+}
+
+impl Reference {
+    pub fn new(kind: Kind) -> Self {
+        Self {
+            kind,
+            style: Style::default(),
+            label: None,
+            layer: Layer::Below,
+            include_in_range: false,
+        }
+    }
+
+    pub fn hline(y: f32) -> Self {
+        Self::new(Kind::Horizontal(y))
+    }
+
+    pub fn vline(x: f32) -> Self {
+        Self::new(Kind::Vertical(x))
+    }
+
+    pub fn hspan(y: RangeInclusive<f32>) -> Self {
+        Self::new(Kind::HorizontalSpan(y))
+    }
+
+    pub fn vspan(x: RangeInclusive<f32>) -> Self {
+        Self::new(Kind::VerticalSpan(x))
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.style.color = Some(color.into());
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.style.width = width;
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.style.opacity = opacity;
+        self
+    }
+
+    pub fn font_size(mut self, size: impl Into<Pixels>) -> Self {
+        self.style.font_size = size.into();
+        self
+    }
+
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn layer(mut self, layer: Layer) -> Self {
+        self.layer = layer;
+        self
+    }
+
+    /// Extends the auto computed range of the chart to contain the reference.
+    pub fn include_in_range(mut self, include: bool) -> Self {
+        self.include_in_range = include;
+        self
+    }
+
+    pub(crate) fn x_range(&self) -> Option<RangeInclusive<f32>> {
+        if !self.include_in_range {
+            return None;
+        }
+
+        match &self.kind {
+            Kind::Vertical(x) => Some(*x..=*x),
+            Kind::VerticalSpan(x) => Some(x.clone()),
+            Kind::Horizontal(_) | Kind::HorizontalSpan(_) => None,
+        }
+    }
+
+    pub(crate) fn y_range(&self) -> Option<RangeInclusive<f32>> {
+        if !self.include_in_range {
+            return None;
+        }
+
+        match &self.kind {
+            Kind::Horizontal(y) => Some(*y..=*y),
+            Kind::HorizontalSpan(y) => Some(y.clone()),
+            Kind::Vertical(_) | Kind::VerticalSpan(_) => None,
+        }
+    }
+
+    pub(crate) fn draw(
+        &self,
+        frame: &mut dyn Target,
+        coordinates: &dyn CoordinateSystem,
+        default_color: Color,
+    ) {
+        let color = self.style.color.unwrap_or(default_color);
+        let bounds = coordinates.bounds();
+        let (x_min, x_max) = (bounds.x, bounds.x + bounds.width);
+        let (y_min, y_max) = (bounds.y, bounds.y + bounds.height);
+
+        let stroke = Stroke::default()
+            .with_width(self.style.width)
+            .with_color(color);
+
+        let span = color.scale_alpha(self.style.opacity);
+        let horizontal = |y| edge(coordinates, (x_min, y), (x_max, y));
+        let vertical = |x| edge(coordinates, (x, y_min), (x, y_max));
+
+        // the label sits at the right end of horizontal and the top of
+        // vertical references
+        let label = match &self.kind {
+            Kind::Horizontal(y) => {
+                if *y < y_min || *y > y_max {
+                    return;
+                }
+
+                frame.stroke(&polyline(horizontal(*y)), stroke);
+
+                (Point::new(x_max, *y), alignment::Horizontal::Right)
+            }
+            Kind::Vertical(x) => {
+                if *x < x_min || *x > x_max {
+                    return;
+                }
+
+                frame.stroke(&polyline(vertical(*x)), stroke);
+
+                (Point::new(*x, y_max), alignment::Horizontal::Left)
+            }
+            Kind::HorizontalSpan(y) => {
+                let (start, end) = (y.start().max(y_min), y.end().min(y_max));
+                if start > end {
+                    return;
+                }
+
+                fill_span(frame, horizontal(start), horizontal(end), span);
+
+                (Point::new(x_max, end), alignment::Horizontal::Right)
+            }
+            Kind::VerticalSpan(x) => {
+                let (start, end) = (x.start().max(x_min), x.end().min(x_max));
+                if start > end {
+                    return;
+                }
+
+                fill_span(frame, vertical(start), vertical(end), span);
+
+                (Point::new(start, y_max), alignment::Horizontal::Left)
+            }
+        };
+
+        let Some(content) = &self.label else {
+            return;
+        };
+        let (position, align_x) = label;
+        // TODO remove magic number,
+        let offset = match align_x {
+            alignment::Horizontal::Right => Vector::new(-4.0, 4.0),
+            _ => Vector::new(4.0, 4.0),
+        };
+
+        frame.fill_text(canvas::Text {
+            content: content.clone(),
+            size: self.style.font_size,
+            position: coordinates.to_screen(position) + offset,
+            color,
+            align_x,
+            align_y: alignment::Vertical::Top,
+            font: Font::MONOSPACE,
+            ..canvas::Text::default()
+        });
+    }
+}
+
+fn fill_span(frame: &mut dyn Target, start: Vec<Point>, end: Vec<Point>, color: Color) {
+    let area = Path::new(|b| {
+        for (i, point) in start.iter().chain(end.iter().rev()).enumerate() {
+            if i == 0 {
+                b.move_to(*point);
+            } else {
+                b.line_to(*point);
+            }
+        }
+        b.close();
+    });
+
+    frame.fill(
+        &area,
+        canvas::Fill {
+            style: canvas::Style::Solid(color),
+            ..Default::default()
+        },
+    );
+}
+
+/// Projects the straight data space line between `from` and `to`.
+fn edge(coordinates: &dyn CoordinateSystem, from: (f32, f32), to: (f32, f32)) -> Vec<Point> {
+    (0..=SAMPLES)
+        .map(|i| {
+            let t = i as f32 / SAMPLES as f32;
+            coordinates.to_screen(Point::new(
+                from.0 + (to.0 - from.0) * t,
+                from.1 + (to.1 - from.1) * t,
+            ))
+        })
+        .collect()
+}
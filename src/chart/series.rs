@@ -1,17 +1,44 @@
+pub mod box_plot;
+pub mod candlestick;
+mod error_bar;
+pub mod heatmap;
+pub mod histogram;
 mod line;
 pub mod point;
+mod stats;
 
+pub use box_plot::BoxPlotSeries;
+pub use candlestick::CandlestickSeries;
+pub use error_bar::ErrorBarSeries;
+pub use heatmap::HeatmapSeries;
+pub use histogram::HistogramSeries;
 pub use line::LineSeries;
 pub use point::PointSeries;
 
-use super::{cartesian::Plane, items};
+use super::{CoordinateSystem, Style, Target, drag, items};
 
-use iced::widget::canvas::{self};
+use iced::Color;
+use iced::widget::canvas::Path;
 
 use std::ops::RangeInclusive;
 
 pub trait Series<SeriesId, ItemId = usize> {
-    fn draw(&self, frame: &mut canvas::Frame, plane: &Plane);
+    /// Draws the series, `color` is the one assigned by the chart palette.
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color);
+    /// Draws what explains the series rather than its data, like a colorbar,
+    /// in the colors of the chart style.
+    fn draw_legend(
+        &self,
+        _frame: &mut dyn Target,
+        _coordinates: &dyn CoordinateSystem,
+        _style: &Style,
+    ) {
+    }
+    /// Whether the series takes its color from the chart palette, i.e. no
+    /// explicit color has been set.
+    fn uses_palette(&self) -> bool {
+        false
+    }
     fn id(&self) -> Option<SeriesId> {
         None
     }
@@ -21,11 +48,70 @@ pub trait Series<SeriesId, ItemId = usize> {
     fn items(&self) -> Option<(SeriesId, Vec<items::Entry<ItemId>>)> {
         None
     }
+    /// How the items of the series may be dragged, `None` if they are fixed.
+    fn draggable(&self) -> Option<&drag::Constraints> {
+        None
+    }
+    /// The visible data, e.g. for copying it to the clipboard.
+    fn table(&self, _coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        None
+    }
     fn x_range(&self) -> RangeInclusive<f32>;
     fn y_range(&self) -> RangeInclusive<f32>;
 }
 
-pub fn line_series<Data>(data: Data) -> LineSeries<Data> {
+/// Values of a series in named columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Table {
+    pub columns: Vec<&'static str>,
+    pub rows: Vec<Vec<f32>>,
+}
+
+impl Table {
+    pub fn new(columns: impl IntoIterator<Item = &'static str>) -> Self {
+        Self {
+            columns: columns.into_iter().collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = f32>) {
+        self.rows.push(row.into_iter().collect());
+    }
+
+    /// Tab separated values, starting with a header row.
+    pub fn to_tsv(&self) -> String {
+        let mut tsv = self.columns.join("\t");
+
+        for row in &self.rows {
+            let row: Vec<_> = row.iter().map(f32::to_string).collect();
+
+            tsv.push('\n');
+            tsv.push_str(&row.join("\t"));
+        }
+
+        tsv
+    }
+}
+
+/// Outline of a data space rectangle, following the projection at its corners.
+fn rectangle(coordinates: &dyn CoordinateSystem, bounds: iced::Rectangle) -> Path {
+    let (left, right) = (bounds.x, bounds.x + bounds.width);
+    let (bottom, top) = (bounds.y, bounds.y + bounds.height);
+
+    Path::new(|b| {
+        b.move_to(coordinates.to_screen(iced::Point::new(left, bottom)));
+        b.line_to(coordinates.to_screen(iced::Point::new(right, bottom)));
+        b.line_to(coordinates.to_screen(iced::Point::new(right, top)));
+        b.line_to(coordinates.to_screen(iced::Point::new(left, top)));
+        b.close();
+    })
+}
+
+pub fn line_series<Id, Data>(data: Data) -> LineSeries<Id, Data>
+where
+    Id: Clone,
+{
     LineSeries::new(data)
 }
 
@@ -36,3 +122,39 @@ where
 {
     PointSeries::new(data)
 }
+
+pub fn box_plot_series<'a, Id, Group, Data>(data: Data) -> BoxPlotSeries<'a, Id, Group, Data>
+where
+    Id: Clone,
+    Data: IntoIterator<Item = Group>,
+{
+    BoxPlotSeries::new(data)
+}
+
+pub fn candlestick_series<Id, Data>(data: Data) -> CandlestickSeries<Id, Data>
+where
+    Id: Clone,
+{
+    CandlestickSeries::new(data)
+}
+
+pub fn error_bar_series<'a, Item, Data>(data: Data) -> ErrorBarSeries<'a, Item, Data>
+where
+    Data: IntoIterator<Item = Item>,
+{
+    ErrorBarSeries::new(data)
+}
+
+pub fn heatmap_series<'a, Id, Data>(data: Data) -> HeatmapSeries<'a, Id, Data>
+where
+    Id: Clone,
+{
+    HeatmapSeries::new(data)
+}
+
+pub fn histogram<Id, Data>(data: Data) -> HistogramSeries<Id, Data>
+where
+    Id: Clone,
+{
+    HistogramSeries::new(data)
+}
@@ -0,0 +1,367 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Point, Rectangle, Size,
+    widget::canvas::{self, Path, Stroke},
+};
+
+use crate::chart::{CoordinateSystem, Target, items};
+
+use super::{Series, Table, point, rectangle, stats};
+
+pub struct BoxPlotSeries<'a, SeriesId, Group, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator<Item = Group>,
+{
+    pub id: Option<SeriesId>,
+    pub data: Data,
+    pub color: Option<Color>,
+    pub fill: Option<Color>,
+    /// Width of a box in data units.
+    pub box_width: f32,
+    pub line_width: f32,
+    whiskers: Whiskers,
+    x_fn: Option<&'a dyn Fn(&Group) -> f32>,
+    outlier_style: point::Style,
+}
+
+/// Where the whiskers end.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Whiskers {
+    /// The most extreme samples within 1.5 IQR of the quartiles, everything
+    /// beyond is an outlier.
+    #[default]
+    Tukey,
+    /// The minimum and maximum sample, there are no outliers.
+    MinMax,
+}
+
+/// The computed statistics of a single group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    pub index: usize,
+    pub x: f32,
+    pub lower_whisker: f32,
+    pub q1: f32,
+    pub median: f32,
+    pub q3: f32,
+    pub upper_whisker: f32,
+    pub outliers: Vec<f32>,
+}
+
+/// A sample outside of the whiskers.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlier {
+    /// The item index reported by [`crate::chart::State::items`].
+    pub index: usize,
+    /// Index of the group the sample belongs to.
+    pub group: usize,
+    pub x: f32,
+    pub value: f32,
+}
+
+impl<'a, SeriesId, Group, Data> BoxPlotSeries<'a, SeriesId, Group, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator<Item = Group>,
+{
+    pub fn new(data: Data) -> Self {
+        Self {
+            id: None,
+            data,
+            color: None,
+            fill: None,
+            box_width: 0.5,
+            line_width: 1.0,
+            whiskers: Whiskers::default(),
+            x_fn: None,
+            outlier_style: point::Style::default(),
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn fill(mut self, color: impl Into<Color>) -> Self {
+        self.fill = Some(color.into());
+        self
+    }
+
+    pub fn box_width(mut self, width: f32) -> Self {
+        self.box_width = width;
+        self
+    }
+
+    pub fn line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    pub fn whiskers(mut self, whiskers: Whiskers) -> Self {
+        self.whiskers = whiskers;
+        self
+    }
+
+    pub fn outlier_style(mut self, style: impl Into<point::Style>) -> Self {
+        self.outlier_style = style.into();
+        self
+    }
+
+    pub fn with_id(mut self, id: SeriesId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    /// Position of each group on the x axis, defaults to the group index.
+    pub fn x(mut self, x_fn: &'a dyn Fn(&Group) -> f32) -> Self {
+        self.x_fn = Some(x_fn);
+        self
+    }
+}
+
+impl<SeriesId, Group, Data> BoxPlotSeries<'_, SeriesId, Group, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator<Item = Group> + Clone,
+    Group: IntoIterator,
+    Group::Item: Into<f32>,
+{
+    /// Computes the statistics of every non-empty group.
+    pub fn summaries(&self) -> Vec<Summary> {
+        self.data
+            .clone()
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, group)| {
+                let x = self.x_fn.map_or(index as f32, |f| f(&group));
+                let samples = stats::sorted(group.into_iter().map(Into::into));
+
+                let (Some(min), Some(max)) = (samples.first(), samples.last()) else {
+                    return None;
+                };
+
+                let q1 = stats::quantile(&samples, 0.25);
+                let median = stats::quantile(&samples, 0.5);
+                let q3 = stats::quantile(&samples, 0.75);
+
+                let (lower_whisker, upper_whisker, outliers) = match self.whiskers {
+                    Whiskers::MinMax => (*min, *max, vec![]),
+                    Whiskers::Tukey => {
+                        let iqr = q3 - q1;
+                        let fences = q1 - 1.5 * iqr..=q3 + 1.5 * iqr;
+
+                        let mut inside = samples.iter().filter(|s| fences.contains(s));
+                        let lower = inside.next().copied().unwrap_or(q1);
+                        let upper = inside.next_back().copied().unwrap_or(lower);
+
+                        let outliers = samples
+                            .iter()
+                            .filter(|s| !fences.contains(s))
+                            .copied()
+                            .collect();
+
+                        (lower, upper, outliers)
+                    }
+                };
+
+                Some(Summary {
+                    index,
+                    x,
+                    lower_whisker,
+                    q1,
+                    median,
+                    q3,
+                    upper_whisker,
+                    outliers,
+                })
+            })
+            .collect()
+    }
+
+    /// All outliers of all groups, in the order they are registered as items.
+    pub fn outliers(&self) -> Vec<Outlier> {
+        self.summaries()
+            .into_iter()
+            .flat_map(|summary| {
+                summary
+                    .outliers
+                    .into_iter()
+                    .map(move |value| (summary.index, summary.x, value))
+            })
+            .enumerate()
+            .map(|(index, (group, x, value))| Outlier {
+                index,
+                group,
+                x,
+                value,
+            })
+            .collect()
+    }
+}
+
+impl<SeriesId, Group, Data> Series<SeriesId> for BoxPlotSeries<'_, SeriesId, Group, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator<Item = Group> + Clone,
+    Group: IntoIterator,
+    Group::Item: Into<f32>,
+{
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color) {
+        let color = self.color.unwrap_or(color);
+
+        let stroke = Stroke::default()
+            .with_width(self.line_width)
+            .with_color(color);
+        let at = |x, y| coordinates.to_screen(Point::new(x, y));
+
+        for summary in self.summaries() {
+            let half_width = self.box_width / 2.0;
+            let half_cap = half_width / 2.0;
+            let (left, center, right) = (summary.x - half_width, summary.x, summary.x + half_width);
+
+            let body = rectangle(
+                coordinates,
+                Rectangle::new(
+                    Point::new(left, summary.q1),
+                    Size::new(self.box_width, summary.q3 - summary.q1),
+                ),
+            );
+            if let Some(fill) = self.fill {
+                frame.fill(
+                    &body,
+                    canvas::Fill {
+                        style: canvas::Style::Solid(fill),
+                        ..Default::default()
+                    },
+                );
+            }
+            frame.stroke(&body, stroke);
+
+            let lines = Path::new(|b| {
+                b.move_to(at(left, summary.median));
+                b.line_to(at(right, summary.median));
+
+                for (from, to) in [
+                    (summary.q1, summary.lower_whisker),
+                    (summary.q3, summary.upper_whisker),
+                ] {
+                    b.move_to(at(center, from));
+                    b.line_to(at(center, to));
+                    b.move_to(at(center - half_cap, to));
+                    b.line_to(at(center + half_cap, to));
+                }
+            });
+            frame.stroke(&lines, stroke);
+        }
+
+        let style = &self.outlier_style;
+        for outlier in self.outliers() {
+            let point = at(outlier.x, outlier.value);
+
+            let path = &Path::circle(point, style.radius);
+
+            frame.fill(
+                path,
+                canvas::Fill {
+                    style: canvas::Style::Solid(style.color.unwrap_or(color)),
+                    ..Default::default()
+                },
+            );
+
+            frame.stroke(
+                path,
+                Stroke::default()
+                    .with_width(style.border)
+                    .with_color(style.border_color.unwrap_or(color)),
+            );
+        }
+    }
+
+    fn uses_palette(&self) -> bool {
+        self.color.is_none()
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let bounds = coordinates.bounds();
+        let mut table = Table::new(["x", "lower_whisker", "q1", "median", "q3", "upper_whisker"]);
+
+        self.summaries()
+            .into_iter()
+            .filter(|summary| summary.x >= bounds.x && summary.x <= bounds.x + bounds.width)
+            .for_each(|summary| {
+                table.push_row([
+                    summary.x,
+                    summary.lower_whisker,
+                    summary.q1,
+                    summary.median,
+                    summary.q3,
+                    summary.upper_whisker,
+                ]);
+            });
+
+        Some(table)
+    }
+
+    fn x_range(&self) -> RangeInclusive<f32> {
+        let half_width = self.box_width / 2.0;
+        let x_min_cur = f32::INFINITY;
+        let x_max_cur = f32::NEG_INFINITY;
+
+        let (x_min, x_max) =
+            self.summaries()
+                .iter()
+                .fold((x_min_cur, x_max_cur), |(x_min, x_max), summary| {
+                    (
+                        x_min.min(summary.x - half_width),
+                        x_max.max(summary.x + half_width),
+                    )
+                });
+
+        x_min..=x_max
+    }
+
+    fn y_range(&self) -> RangeInclusive<f32> {
+        let y_min_cur = f32::INFINITY;
+        let y_max_cur = f32::NEG_INFINITY;
+
+        let (y_min, y_max) =
+            self.summaries()
+                .iter()
+                .fold((y_min_cur, y_max_cur), |(y_min, y_max), summary| {
+                    summary.outliers.iter().fold(
+                        (
+                            y_min.min(summary.lower_whisker),
+                            y_max.max(summary.upper_whisker),
+                        ),
+                        |(y_min, y_max), outlier| (y_min.min(*outlier), y_max.max(*outlier)),
+                    )
+                });
+
+        y_min..=y_max
+    }
+
+    fn id(&self) -> Option<SeriesId> {
+        self.id.clone()
+    }
+
+    fn collision_box(&self) -> Option<iced::Rectangle> {
+        Some(iced::Rectangle::with_radius(self.outlier_style.radius))
+    }
+
+    fn items(&self) -> Option<(SeriesId, Vec<items::Entry<usize>>)> {
+        let id = self.id.clone()?;
+
+        let items = self
+            .outliers()
+            .into_iter()
+            .map(|outlier| {
+                items::Entry::new(outlier.index, iced::Point::new(outlier.x, outlier.value))
+            })
+            .collect();
+
+        Some((id, items))
+    }
+}
@@ -0,0 +1,306 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Point, Rectangle, Size,
+    widget::canvas::{self, Path, Stroke},
+};
+
+use crate::chart::{CoordinateSystem, Target, items};
+
+use super::{Series, Table, rectangle};
+
+/// Open, high, low and close values of a single period, positioned at `x`.
+///
+/// Time axes work best with `x` relative to some epoch of your choice (e.g.
+/// minutes since the session start), as an `f32` can't represent absolute
+/// timestamps precisely. Use [`crate::chart::Labels::format`] to render them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ohlc {
+    pub x: f32,
+    pub open: f32,
+    pub high: f32,
+    pub low: f32,
+    pub close: f32,
+}
+
+impl Ohlc {
+    pub fn is_up(&self) -> bool {
+        self.close >= self.open
+    }
+}
+
+impl From<(f32, f32, f32, f32, f32)> for Ohlc {
+    fn from((x, open, high, low, close): (f32, f32, f32, f32, f32)) -> Self {
+        Self {
+            x,
+            open,
+            high,
+            low,
+            close,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Kind {
+    /// A body between open and close, with wicks to high and low.
+    #[default]
+    Candlestick,
+    /// A vertical bar from low to high, with open ticking left and close ticking right.
+    Bar,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Body {
+    #[default]
+    Filled,
+    Hollow,
+}
+
+pub struct CandlestickSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub id: Option<SeriesId>,
+    pub data: Data,
+    pub up_color: Color,
+    pub down_color: Color,
+    pub line_width: f32,
+    kind: Kind,
+    body: Body,
+    width: Option<f32>,
+}
+
+impl<SeriesId, Data> CandlestickSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub fn new(data: Data) -> Self {
+        Self {
+            id: None,
+            data,
+            up_color: Color::from_rgb8(38, 166, 154),
+            down_color: Color::from_rgb8(239, 83, 80),
+            line_width: 1.0,
+            kind: Kind::default(),
+            body: Body::default(),
+            width: None,
+        }
+    }
+
+    pub fn up_color(mut self, color: impl Into<Color>) -> Self {
+        self.up_color = color.into();
+        self
+    }
+
+    pub fn down_color(mut self, color: impl Into<Color>) -> Self {
+        self.down_color = color.into();
+        self
+    }
+
+    pub fn line_width(mut self, width: f32) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    pub fn kind(mut self, kind: Kind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    pub fn body(mut self, body: Body) -> Self {
+        self.body = body;
+        self
+    }
+
+    /// Width of a candle in data units, defaults to 70% of the smallest
+    /// distance between two neighbouring candles.
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn with_id(mut self, id: SeriesId) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl<SeriesId, Data> CandlestickSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: Into<Ohlc>,
+{
+    fn candles(&self) -> impl Iterator<Item = Ohlc> {
+        self.data.clone().into_iter().map(Into::into)
+    }
+
+    fn candle_width(&self) -> f32 {
+        if let Some(width) = self.width {
+            return width;
+        }
+
+        let (spacing, _) = self
+            .candles()
+            .fold((f32::INFINITY, None), |(spacing, prev), candle| {
+                let spacing = match prev {
+                    Some(prev) if candle.x != prev => spacing.min((candle.x - prev).abs()),
+                    _ => spacing,
+                };
+
+                (spacing, Some(candle.x))
+            });
+
+        if spacing.is_finite() {
+            spacing * 0.7
+        } else {
+            0.7
+        }
+    }
+}
+
+impl<SeriesId, Data> Series<SeriesId> for CandlestickSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: Into<Ohlc>,
+{
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, _color: Color) {
+        let half_width = self.candle_width() / 2.0;
+        let bounds = coordinates.bounds();
+        let at = |x, y| coordinates.to_screen(Point::new(x, y));
+
+        let visible = self.candles().filter(|candle| {
+            candle.x + half_width >= bounds.x && candle.x - half_width <= bounds.x + bounds.width
+        });
+
+        for candle in visible {
+            let color = if candle.is_up() {
+                self.up_color
+            } else {
+                self.down_color
+            };
+            let stroke = Stroke::default()
+                .with_width(self.line_width)
+                .with_color(color);
+
+            let left = candle.x - half_width;
+            let right = candle.x + half_width;
+            let center = candle.x;
+
+            match self.kind {
+                Kind::Bar => {
+                    let path = Path::new(|b| {
+                        b.move_to(at(center, candle.high));
+                        b.line_to(at(center, candle.low));
+                        b.move_to(at(left, candle.open));
+                        b.line_to(at(center, candle.open));
+                        b.move_to(at(center, candle.close));
+                        b.line_to(at(right, candle.close));
+                    });
+                    frame.stroke(&path, stroke);
+                }
+                Kind::Candlestick => {
+                    let body_bottom = candle.open.min(candle.close);
+                    let body_top = candle.open.max(candle.close);
+
+                    let wicks = Path::new(|b| {
+                        b.move_to(at(center, candle.high));
+                        b.line_to(at(center, body_top));
+                        b.move_to(at(center, body_bottom));
+                        b.line_to(at(center, candle.low));
+                    });
+                    frame.stroke(&wicks, stroke);
+
+                    let body = rectangle(
+                        coordinates,
+                        Rectangle::new(
+                            Point::new(left, body_bottom),
+                            Size::new(right - left, body_top - body_bottom),
+                        ),
+                    );
+                    if self.body == Body::Filled {
+                        frame.fill(
+                            &body,
+                            canvas::Fill {
+                                style: canvas::Style::Solid(color),
+                                ..Default::default()
+                            },
+                        );
+                    }
+                    frame.stroke(&body, stroke);
+                }
+            }
+        }
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let bounds = coordinates.bounds();
+        let mut table = Table::new(["x", "open", "high", "low", "close"]);
+
+        self.candles()
+            .filter(|candle| candle.x >= bounds.x && candle.x <= bounds.x + bounds.width)
+            .for_each(|c| table.push_row([c.x, c.open, c.high, c.low, c.close]));
+
+        Some(table)
+    }
+
+    fn x_range(&self) -> RangeInclusive<f32> {
+        let half_width = self.candle_width() / 2.0;
+        let x_min_cur = f32::INFINITY;
+        let x_max_cur = f32::NEG_INFINITY;
+
+        let (x_min, x_max) =
+            self.candles()
+                .fold((x_min_cur, x_max_cur), |(x_min, x_max), candle| {
+                    (
+                        x_min.min(candle.x - half_width),
+                        x_max.max(candle.x + half_width),
+                    )
+                });
+
+        x_min..=x_max
+    }
+
+    fn y_range(&self) -> RangeInclusive<f32> {
+        let y_min_cur = f32::INFINITY;
+        let y_max_cur = f32::NEG_INFINITY;
+
+        let (y_min, y_max) = self
+            .candles()
+            .fold((y_min_cur, y_max_cur), |(y_min, y_max), candle| {
+                (y_min.min(candle.low), y_max.max(candle.high))
+            });
+
+        y_min..=y_max
+    }
+
+    fn id(&self) -> Option<SeriesId> {
+        self.id.clone()
+    }
+
+    fn collision_box(&self) -> Option<iced::Rectangle> {
+        Some(iced::Rectangle::with_radius(0.5))
+    }
+
+    fn items(&self) -> Option<(SeriesId, Vec<items::Entry<usize>>)> {
+        let id = self.id.clone()?;
+        let width = self.candle_width();
+
+        let items = self
+            .candles()
+            .enumerate()
+            .map(|(index, candle)| {
+                let bounds = Rectangle::new(
+                    Point::new(candle.x - width / 2.0, candle.low),
+                    Size::new(width, candle.high - candle.low),
+                );
+
+                items::Entry::area(index, bounds)
+            })
+            .collect();
+
+        Some((id, items))
+    }
+}
@@ -0,0 +1,237 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Point,
+    widget::canvas::{Path, Stroke},
+};
+
+use crate::chart::{CoordinateSystem, Target};
+
+use super::{Series, Table};
+
+pub struct ErrorBarSeries<'a, Item, Data>
+where
+    Data: IntoIterator<Item = Item>,
+{
+    pub data: Data,
+    pub color: Option<Color>,
+    pub width: f32,
+    pub cap_width: f32,
+    x_fn: Option<&'a dyn Fn(&Item) -> f32>,
+    y_fn: Option<&'a dyn Fn(&Item) -> f32>,
+    x_error: Option<Error<'a, Item>>,
+    y_error: Option<Error<'a, Item>>,
+}
+
+/// The error extent of an item, relative to its value.
+enum Error<'a, Item> {
+    Symmetric(&'a dyn Fn(&Item) -> f32),
+    /// `(minus, plus)`
+    Asymmetric(&'a dyn Fn(&Item) -> (f32, f32)),
+}
+
+impl<Item> Error<'_, Item> {
+    fn extent(&self, item: &Item) -> (f32, f32) {
+        let (minus, plus) = match self {
+            Error::Symmetric(f) => {
+                let error = f(item);
+                (error, error)
+            }
+            Error::Asymmetric(f) => f(item),
+        };
+
+        (minus.abs(), plus.abs())
+    }
+}
+
+impl<'a, Item, Data> ErrorBarSeries<'a, Item, Data>
+where
+    Data: IntoIterator<Item = Item>,
+{
+    pub fn new(data: Data) -> Self {
+        Self {
+            data,
+            color: None,
+            width: 1.0,
+            cap_width: 6.0,
+            x_fn: None,
+            y_fn: None,
+            x_error: None,
+            y_error: None,
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Width of the caps in pixels, `0.0` disables them.
+    pub fn cap_width(mut self, cap_width: f32) -> Self {
+        self.cap_width = cap_width;
+        self
+    }
+
+    pub fn x(mut self, x_fn: &'a dyn Fn(&Item) -> f32) -> Self {
+        self.x_fn = Some(x_fn);
+        self
+    }
+
+    pub fn y(mut self, y_fn: &'a dyn Fn(&Item) -> f32) -> Self {
+        self.y_fn = Some(y_fn);
+        self
+    }
+
+    /// Horizontal error bar extending the same amount to both sides.
+    pub fn x_error(mut self, error_fn: &'a dyn Fn(&Item) -> f32) -> Self {
+        self.x_error = Some(Error::Symmetric(error_fn));
+        self
+    }
+
+    /// Horizontal error bar with distinct `(minus, plus)` extents.
+    pub fn x_error_asymmetric(mut self, error_fn: &'a dyn Fn(&Item) -> (f32, f32)) -> Self {
+        self.x_error = Some(Error::Asymmetric(error_fn));
+        self
+    }
+
+    /// Vertical error bar extending the same amount to both sides.
+    pub fn y_error(mut self, error_fn: &'a dyn Fn(&Item) -> f32) -> Self {
+        self.y_error = Some(Error::Symmetric(error_fn));
+        self
+    }
+
+    /// Vertical error bar with distinct `(minus, plus)` extents.
+    pub fn y_error_asymmetric(mut self, error_fn: &'a dyn Fn(&Item) -> (f32, f32)) -> Self {
+        self.y_error = Some(Error::Asymmetric(error_fn));
+        self
+    }
+}
+
+impl<Item, Data> ErrorBarSeries<'_, Item, Data>
+where
+    Data: IntoIterator<Item = Item> + Clone,
+    Item: Into<(f32, f32)>,
+{
+    /// Yields the value and the horizontal and vertical extent of each item.
+    fn extents(
+        &self,
+    ) -> impl Iterator<Item = (Point, RangeInclusive<f32>, RangeInclusive<f32>)> + '_ {
+        self.data.clone().into_iter().map(|item| {
+            let x = self.x_fn.as_ref().map(|f| f(&item));
+            let y = self.y_fn.as_ref().map(|f| f(&item));
+
+            let x_error = self.x_error.as_ref().map(|e| e.extent(&item));
+            let y_error = self.y_error.as_ref().map(|e| e.extent(&item));
+
+            let p = item.into();
+            let x = x.unwrap_or(p.0);
+            let y = y.unwrap_or(p.1);
+
+            let (x_minus, x_plus) = x_error.unwrap_or_default();
+            let (y_minus, y_plus) = y_error.unwrap_or_default();
+
+            let x_extent = x - x_minus..=x + x_plus;
+            let y_extent = y - y_minus..=y + y_plus;
+
+            (Point::new(x, y), x_extent, y_extent)
+        })
+    }
+}
+
+impl<Id, Item, Data> Series<Id> for ErrorBarSeries<'_, Item, Data>
+where
+    Data: IntoIterator<Item = Item> + Clone,
+    Item: Into<(f32, f32)>,
+{
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color) {
+        let color = self.color.unwrap_or(color);
+
+        let stroke = Stroke::default().with_width(self.width).with_color(color);
+        let half_cap = self.cap_width / 2.0;
+
+        for (point, x_extent, y_extent) in self.extents() {
+            if self.y_error.is_some() {
+                let bottom = coordinates.to_screen(Point::new(point.x, *y_extent.start()));
+                let top = coordinates.to_screen(Point::new(point.x, *y_extent.end()));
+
+                let path = Path::new(|b| {
+                    b.move_to(bottom);
+                    b.line_to(top);
+
+                    if half_cap > 0.0 {
+                        for cap in [bottom, top] {
+                            b.move_to(Point::new(cap.x - half_cap, cap.y));
+                            b.line_to(Point::new(cap.x + half_cap, cap.y));
+                        }
+                    }
+                });
+                frame.stroke(&path, stroke);
+            }
+
+            if self.x_error.is_some() {
+                let left = coordinates.to_screen(Point::new(*x_extent.start(), point.y));
+                let right = coordinates.to_screen(Point::new(*x_extent.end(), point.y));
+
+                let path = Path::new(|b| {
+                    b.move_to(left);
+                    b.line_to(right);
+
+                    if half_cap > 0.0 {
+                        for cap in [left, right] {
+                            b.move_to(Point::new(cap.x, cap.y - half_cap));
+                            b.line_to(Point::new(cap.x, cap.y + half_cap));
+                        }
+                    }
+                });
+                frame.stroke(&path, stroke);
+            }
+        }
+    }
+
+    fn uses_palette(&self) -> bool {
+        self.color.is_none()
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let mut table = Table::new(["x", "y", "x_min", "x_max", "y_min", "y_max"]);
+
+        self.extents()
+            .filter(|(point, _, _)| coordinates.is_visible(*point))
+            .for_each(|(point, x, y)| {
+                table.push_row([point.x, point.y, *x.start(), *x.end(), *y.start(), *y.end()]);
+            });
+
+        Some(table)
+    }
+
+    fn x_range(&self) -> RangeInclusive<f32> {
+        let x_min_cur = f32::INFINITY;
+        let x_max_cur = f32::NEG_INFINITY;
+
+        let (x_min, x_max) = self
+            .extents()
+            .fold((x_min_cur, x_max_cur), |(x_min, x_max), (_, cur_x, _)| {
+                (x_min.min(*cur_x.start()), x_max.max(*cur_x.end()))
+            });
+
+        x_min..=x_max
+    }
+
+    fn y_range(&self) -> RangeInclusive<f32> {
+        let y_min_cur = f32::INFINITY;
+        let y_max_cur = f32::NEG_INFINITY;
+
+        let (y_min, y_max) = self
+            .extents()
+            .fold((y_min_cur, y_max_cur), |(y_min, y_max), (_, _, cur_y)| {
+                (y_min.min(*cur_y.start()), y_max.max(*cur_y.end()))
+            });
+
+        y_min..=y_max
+    }
+}
@@ -0,0 +1,372 @@
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Font, Point, Rectangle, Size, alignment,
+    widget::canvas::{self, Path, Stroke},
+};
+
+use crate::chart::{CoordinateSystem, Style, Target, colormap::Colormap, items};
+
+use super::{Series, Table, rectangle};
+
+/// Renders a matrix of values as colored cells.
+///
+/// Row `0` is placed at the start of the y extent, column `0` at the start of
+/// the x extent.
+///
+/// Hovered cells are reported by [`crate::chart::State::items`] as a single
+/// index per cell, [`Self::cell`] turns it back into a row and column.
+pub struct HeatmapSeries<'a, SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub id: Option<SeriesId>,
+    pub data: Data,
+    colormap: Colormap<'a>,
+    x_extent: Option<RangeInclusive<f32>>,
+    y_extent: Option<RangeInclusive<f32>>,
+    value_range: Option<RangeInclusive<f32>>,
+    colorbar: Option<Colorbar>,
+}
+
+/// A legend of the colormap, drawn into the right margin of the chart.
+#[derive(Debug, Clone)]
+pub struct Colorbar {
+    pub width: f32,
+    pub spacing: f32,
+    /// Color of the outline and the labels, defaults to the axis and label
+    /// colors of the chart style.
+    pub color: Option<Color>,
+    pub font_size: iced::Pixels,
+}
+
+/// A single cell of the matrix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cell {
+    pub row: usize,
+    pub col: usize,
+    pub value: f32,
+    pub bounds: Rectangle,
+}
+
+impl<'a, SeriesId, Data> HeatmapSeries<'a, SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub fn new(data: Data) -> Self {
+        Self {
+            id: None,
+            data,
+            colormap: Colormap::default(),
+            x_extent: None,
+            y_extent: None,
+            value_range: None,
+            colorbar: None,
+        }
+    }
+
+    pub fn colormap(mut self, colormap: Colormap<'a>) -> Self {
+        self.colormap = colormap;
+        self
+    }
+
+    /// Data range covered by the columns, defaults to `0.0..=columns`.
+    pub fn x_extent(mut self, extent: RangeInclusive<f32>) -> Self {
+        self.x_extent = Some(extent);
+        self
+    }
+
+    /// Data range covered by the rows, defaults to `0.0..=rows`.
+    pub fn y_extent(mut self, extent: RangeInclusive<f32>) -> Self {
+        self.y_extent = Some(extent);
+        self
+    }
+
+    /// Values mapped to the start and end of the colormap, defaults to the
+    /// minimum and maximum value.
+    pub fn value_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.value_range = Some(range);
+        self
+    }
+
+    pub fn colorbar(mut self, colorbar: Colorbar) -> Self {
+        self.colorbar = Some(colorbar);
+        self
+    }
+
+    pub fn with_id(mut self, id: SeriesId) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl<SeriesId, Data> HeatmapSeries<'_, SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: IntoIterator,
+    <Data::Item as IntoIterator>::Item: Into<f32>,
+{
+    fn rows(&self) -> Vec<Vec<f32>> {
+        self.data
+            .clone()
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect()
+    }
+
+    fn dimensions(rows: &[Vec<f32>]) -> (usize, usize) {
+        let columns = rows.iter().map(Vec::len).max().unwrap_or_default();
+
+        (rows.len(), columns)
+    }
+
+    fn extents(&self, rows: usize, columns: usize) -> (RangeInclusive<f32>, RangeInclusive<f32>) {
+        (
+            self.x_extent.clone().unwrap_or(0.0..=columns as f32),
+            self.y_extent.clone().unwrap_or(0.0..=rows as f32),
+        )
+    }
+
+    fn value_range_of(&self, rows: &[Vec<f32>]) -> RangeInclusive<f32> {
+        self.value_range.clone().unwrap_or_else(|| {
+            let (min, max) = rows
+                .iter()
+                .flatten()
+                .filter(|v| v.is_finite())
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), v| {
+                    (min.min(*v), max.max(*v))
+                });
+
+            min..=max
+        })
+    }
+
+    /// All cells with their bounds in data coordinates.
+    ///
+    /// The item index reported by [`crate::chart::State::items`] is
+    /// `row * columns + col`, see [`Self::cell`].
+    pub fn cells(&self) -> Vec<Cell> {
+        let rows = self.rows();
+        let (row_count, columns) = Self::dimensions(&rows);
+        let (x_extent, y_extent) = self.extents(row_count, columns);
+
+        let (x_start, y_start) = (*x_extent.start(), *y_extent.start());
+        let width = (x_extent.end() - x_start) / columns.max(1) as f32;
+        let height = (y_extent.end() - y_start) / row_count.max(1) as f32;
+
+        rows.into_iter()
+            .enumerate()
+            .flat_map(|(row, values)| {
+                values.into_iter().enumerate().map(move |(col, value)| {
+                    let position =
+                        Point::new(x_start + col as f32 * width, y_start + row as f32 * height);
+
+                    Cell {
+                        row,
+                        col,
+                        value,
+                        bounds: Rectangle::new(position, Size::new(width, height)),
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// Looks up the cell of an item index, e.g. of a hovered item.
+    pub fn cell(&self, index: usize) -> Option<Cell> {
+        let rows = self.rows();
+        let (row_count, columns) = Self::dimensions(&rows);
+        let (row, col) = (index / columns.max(1), index % columns.max(1));
+        let value = *rows.get(row)?.get(col)?;
+
+        let (x_extent, y_extent) = self.extents(row_count, columns);
+        let width = (x_extent.end() - x_extent.start()) / columns.max(1) as f32;
+        let height = (y_extent.end() - y_extent.start()) / row_count.max(1) as f32;
+        let position = Point::new(
+            x_extent.start() + col as f32 * width,
+            y_extent.start() + row as f32 * height,
+        );
+
+        Some(Cell {
+            row,
+            col,
+            value,
+            bounds: Rectangle::new(position, Size::new(width, height)),
+        })
+    }
+
+    fn draw_colorbar(
+        &self,
+        frame: &mut dyn Target,
+        coordinates: &dyn CoordinateSystem,
+        colorbar: &Colorbar,
+        range: &RangeInclusive<f32>,
+        style: &Style,
+    ) {
+        let bounds = coordinates.bounds();
+        let corners = [
+            Point::new(bounds.x, bounds.y),
+            Point::new(bounds.x + bounds.width, bounds.y),
+            Point::new(bounds.x, bounds.y + bounds.height),
+            Point::new(bounds.x + bounds.width, bounds.y + bounds.height),
+        ]
+        .map(|corner| coordinates.to_screen(corner));
+
+        let (right, top, bottom) = corners.iter().fold(
+            (f32::NEG_INFINITY, f32::INFINITY, f32::NEG_INFINITY),
+            |(right, top, bottom), corner| {
+                (right.max(corner.x), top.min(corner.y), bottom.max(corner.y))
+            },
+        );
+        let left = right + colorbar.spacing;
+
+        let steps = (bottom - top).max(1.0).ceil() as usize;
+        let step_height = (bottom - top) / steps as f32;
+        for step in 0..steps {
+            let t = 1.0 - (step as f32 + 0.5) / steps as f32;
+
+            frame.fill_rectangle(
+                Point::new(left, top + step as f32 * step_height),
+                Size::new(colorbar.width, step_height),
+                self.colormap.color(t).into(),
+            );
+        }
+
+        frame.stroke(
+            &Path::rectangle(
+                Point::new(left, top),
+                Size::new(colorbar.width, bottom - top),
+            ),
+            Stroke::default()
+                .with_width(1.0)
+                .with_color(colorbar.color.unwrap_or(style.axis)),
+        );
+
+        for (value, y) in [(range.end(), top), (range.start(), bottom)] {
+            frame.fill_text(canvas::Text {
+                content: format!("{value}"),
+                size: colorbar.font_size,
+                position: Point {
+                    x: left + colorbar.width + colorbar.spacing,
+                    y,
+                },
+                color: colorbar.color.unwrap_or(style.label),
+                align_x: alignment::Horizontal::Left,
+                align_y: alignment::Vertical::Center,
+                font: Font::MONOSPACE,
+                ..canvas::Text::default()
+            });
+        }
+    }
+}
+
+impl<SeriesId, Data> Series<SeriesId> for HeatmapSeries<'_, SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: IntoIterator,
+    <Data::Item as IntoIterator>::Item: Into<f32>,
+{
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, _color: Color) {
+        let range = self.value_range_of(&self.rows());
+        let span = range.end() - range.start();
+        let bounds = coordinates.bounds();
+
+        let visible = self
+            .cells()
+            .into_iter()
+            .filter(|cell| cell.value.is_finite() && cell.bounds.intersects(&bounds));
+
+        for cell in visible {
+            let t = if span > 0.0 {
+                (cell.value - range.start()) / span
+            } else {
+                0.5
+            };
+
+            frame.fill(
+                &rectangle(coordinates, cell.bounds),
+                self.colormap.color(t).into(),
+            );
+        }
+    }
+
+    fn draw_legend(
+        &self,
+        frame: &mut dyn Target,
+        coordinates: &dyn CoordinateSystem,
+        style: &Style,
+    ) {
+        if let Some(colorbar) = &self.colorbar {
+            let range = self.value_range_of(&self.rows());
+
+            self.draw_colorbar(frame, coordinates, colorbar, &range, style);
+        }
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let bounds = coordinates.bounds();
+        let mut table = Table::new(["row", "column", "x", "y", "value"]);
+
+        self.cells()
+            .into_iter()
+            .filter(|cell| cell.bounds.intersects(&bounds))
+            .for_each(|cell| {
+                let center = cell.bounds.center();
+                table.push_row([
+                    cell.row as f32,
+                    cell.col as f32,
+                    center.x,
+                    center.y,
+                    cell.value,
+                ]);
+            });
+
+        Some(table)
+    }
+
+    fn x_range(&self) -> RangeInclusive<f32> {
+        let (rows, columns) = Self::dimensions(&self.rows());
+
+        self.extents(rows, columns).0
+    }
+
+    fn y_range(&self) -> RangeInclusive<f32> {
+        let (rows, columns) = Self::dimensions(&self.rows());
+
+        self.extents(rows, columns).1
+    }
+
+    fn id(&self) -> Option<SeriesId> {
+        self.id.clone()
+    }
+
+    fn collision_box(&self) -> Option<iced::Rectangle> {
+        Some(iced::Rectangle::with_radius(0.5))
+    }
+
+    fn items(&self) -> Option<(SeriesId, Vec<items::Entry<usize>>)> {
+        let id = self.id.clone()?;
+        let (_, columns) = Self::dimensions(&self.rows());
+
+        let items = self
+            .cells()
+            .into_iter()
+            .map(|cell| items::Entry::area(cell.row * columns + cell.col, cell.bounds))
+            .collect();
+
+        Some((id, items))
+    }
+}
+
+impl Default for Colorbar {
+    fn default() -> Self {
+        Self {
+            width: 12.0,
+            spacing: 8.0,
+            color: None,
+            font_size: 12.into(),
+        }
+    }
+}
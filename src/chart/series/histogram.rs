@@ -0,0 +1,442 @@
+use std::cell::OnceCell;
+use std::ops::RangeInclusive;
+
+use iced::{
+    Color, Point, Rectangle, Size,
+    widget::canvas::{self, Stroke},
+};
+
+use crate::chart::{CoordinateSystem, Target, items};
+
+use super::{Series, Table, rectangle, stats};
+
+pub struct HistogramSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub id: Option<SeriesId>,
+    pub data: Data,
+    pub color: Option<Color>,
+    pub border_color: Option<Color>,
+    pub border: f32,
+    binning: Binning,
+    normalization: Normalization,
+    /// Computed on first use, reset when the binning changes.
+    bins: OnceCell<Vec<Bin>>,
+}
+
+/// How the samples are divided into bins.
+#[derive(Debug, Clone, Default)]
+pub enum Binning {
+    /// `log2(n) + 1` bins.
+    #[default]
+    Sturges,
+    /// Bin width of `3.49 * σ * n^(-1/3)`.
+    Scott,
+    /// Bin width of `2 * IQR * n^(-1/3)`.
+    FreedmanDiaconis,
+    /// A fixed amount of equal-width bins.
+    Count(usize),
+    /// Explicit bin edges, `n + 1` edges make up `n` bins.
+    ///
+    /// The edges are sorted and duplicates are dropped, so no bin is empty.
+    Edges(Vec<f32>),
+}
+
+/// The value of each bin, relative to the samples within the bins.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Normalization {
+    /// Number of samples in the bin.
+    #[default]
+    Count,
+    /// Probability density, so that the area of all bars sums up to one.
+    Density,
+    /// Fraction of samples in this and all preceding bins.
+    Cumulative,
+}
+
+/// A computed bin, covering `start..end` (the last one includes `end`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bin {
+    pub index: usize,
+    pub start: f32,
+    pub end: f32,
+    pub count: usize,
+    pub value: f32,
+}
+
+impl Bin {
+    /// The area of the bar in data coordinates.
+    pub fn bounds(&self) -> Rectangle {
+        Rectangle::new(
+            Point::new(self.start, 0.0),
+            Size::new(self.end - self.start, self.value),
+        )
+    }
+}
+
+impl<SeriesId, Data> HistogramSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub fn new(data: Data) -> Self {
+        Self {
+            id: None,
+            data,
+            color: None,
+            border_color: None,
+            border: 0.0,
+            binning: Binning::default(),
+            normalization: Normalization::default(),
+            bins: OnceCell::new(),
+        }
+    }
+
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn border_color(mut self, color: impl Into<Color>) -> Self {
+        self.border_color = Some(color.into());
+        self
+    }
+
+    pub fn border(mut self, border: f32) -> Self {
+        self.border = border;
+        self
+    }
+
+    pub fn binning(mut self, binning: Binning) -> Self {
+        self.binning = binning;
+        self.bins = OnceCell::new();
+        self
+    }
+
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self.bins = OnceCell::new();
+        self
+    }
+
+    pub fn with_id(mut self, id: SeriesId) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl<SeriesId, Data> HistogramSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: Into<f32>,
+{
+    /// The bins of the data, computed once on first use.
+    ///
+    /// The index of a [`Bin`] matches the item index reported by [`crate::chart::State::items`].
+    pub fn bins(&self) -> &[Bin] {
+        self.bins.get_or_init(|| self.compute_bins())
+    }
+
+    fn compute_bins(&self) -> Vec<Bin> {
+        let samples = stats::sorted(self.data.clone().into_iter().map(Into::into));
+        let edges = self.edges(&samples);
+
+        if edges.len() < 2 {
+            return vec![];
+        }
+
+        let mut counts = vec![0; edges.len() - 1];
+        let last = edges[edges.len() - 1];
+        for sample in &samples {
+            if *sample < edges[0] || *sample > last {
+                continue;
+            }
+
+            let index = edges
+                .partition_point(|edge| edge <= sample)
+                .clamp(1, counts.len())
+                - 1;
+            counts[index] += 1;
+        }
+
+        // samples outside of explicit edges are left out of the normalization
+        let total = counts.iter().sum::<usize>() as f32;
+        let mut cumulative = 0;
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(index, count)| {
+                let start = edges[index];
+                let end = edges[index + 1];
+                cumulative += count;
+
+                let value = match self.normalization {
+                    _ if total == 0.0 => 0.0,
+                    Normalization::Count => count as f32,
+                    Normalization::Density => count as f32 / (total * (end - start)),
+                    Normalization::Cumulative => cumulative as f32 / total,
+                };
+
+                Bin {
+                    index,
+                    start,
+                    end,
+                    count,
+                    value,
+                }
+            })
+            .collect()
+    }
+
+    fn edges(&self, samples: &[f32]) -> Vec<f32> {
+        if let Binning::Edges(edges) = &self.binning {
+            let mut edges = stats::sorted(edges.iter().copied());
+            edges.dedup();
+
+            return edges;
+        }
+
+        let (Some(min), Some(max)) = (samples.first(), samples.last()) else {
+            return vec![];
+        };
+
+        let range = max - min;
+        if range == 0.0 {
+            return vec![min - 0.5, max + 0.5];
+        }
+
+        let len = samples.len() as f32;
+        let width_to_count = |width: f32| {
+            if width > 0.0 {
+                (range / width).ceil() as usize
+            } else {
+                1
+            }
+        };
+
+        let count = match self.binning {
+            Binning::Sturges => len.log2().ceil() as usize + 1,
+            Binning::Scott => {
+                width_to_count(3.49 * stats::standard_deviation(samples) / len.cbrt())
+            }
+            Binning::FreedmanDiaconis => {
+                let iqr = stats::quantile(samples, 0.75) - stats::quantile(samples, 0.25);
+                width_to_count(2.0 * iqr / len.cbrt())
+            }
+            Binning::Count(count) => count,
+            Binning::Edges(_) => unreachable!(),
+        }
+        .max(1);
+
+        let width = range / count as f32;
+        (0..=count).map(|i| min + i as f32 * width).collect()
+    }
+}
+
+impl<SeriesId, Data> Series<SeriesId> for HistogramSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+    Data: IntoIterator + Clone,
+    Data::Item: Into<f32>,
+{
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color) {
+        let color = self.color.unwrap_or(color);
+
+        for bin in self.bins() {
+            let path = rectangle(coordinates, bin.bounds());
+
+            frame.fill(
+                &path,
+                canvas::Fill {
+                    style: canvas::Style::Solid(color),
+                    ..Default::default()
+                },
+            );
+
+            if self.border > 0.0 {
+                frame.stroke(
+                    &path,
+                    Stroke::default()
+                        .with_width(self.border)
+                        .with_color(self.border_color.unwrap_or(color)),
+                );
+            }
+        }
+    }
+
+    fn uses_palette(&self) -> bool {
+        self.color.is_none()
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let bounds = coordinates.bounds();
+        let mut table = Table::new(["start", "end", "count", "value"]);
+
+        self.bins()
+            .iter()
+            .filter(|bin| bin.end >= bounds.x && bin.start <= bounds.x + bounds.width)
+            .for_each(|bin| table.push_row([bin.start, bin.end, bin.count as f32, bin.value]));
+
+        Some(table)
+    }
+
+    fn x_range(&self) -> RangeInclusive<f32> {
+        let bins = self.bins();
+
+        match (bins.first(), bins.last()) {
+            (Some(first), Some(last)) => first.start..=last.end,
+            _ => f32::INFINITY..=f32::NEG_INFINITY,
+        }
+    }
+
+    fn y_range(&self) -> RangeInclusive<f32> {
+        let y_max = self
+            .bins()
+            .iter()
+            .fold(f32::NEG_INFINITY, |y_max, bin| y_max.max(bin.value));
+
+        0.0..=y_max
+    }
+
+    fn id(&self) -> Option<SeriesId> {
+        self.id.clone()
+    }
+
+    fn collision_box(&self) -> Option<iced::Rectangle> {
+        Some(iced::Rectangle::with_radius(0.5))
+    }
+
+    fn items(&self) -> Option<(SeriesId, Vec<items::Entry<usize>>)> {
+        let id = self.id.clone()?;
+
+        let items = self
+            .bins()
+            .iter()
+            .map(|bin| items::Entry::area(bin.index, bin.bounds()))
+            .collect();
+
+        Some((id, items))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn histogram(samples: &[f32], binning: Binning) -> HistogramSeries<(), Vec<f32>> {
+        HistogramSeries::new(samples.to_vec()).binning(binning)
+    }
+
+    fn edges(bins: &[Bin]) -> Vec<f32> {
+        bins.iter()
+            .map(|bin| bin.start)
+            .chain(bins.last().map(|bin| bin.end))
+            .collect()
+    }
+
+    #[test]
+    fn bin_counts() {
+        let samples: Vec<f32> = (0..10).map(|i| i as f32).collect();
+
+        let count = |binning| histogram(&samples, binning).bins().len();
+        assert_eq!(count(Binning::Sturges), 5);
+        assert_eq!(count(Binning::Scott), 2);
+        assert_eq!(count(Binning::FreedmanDiaconis), 3);
+        assert_eq!(count(Binning::Count(4)), 4);
+    }
+
+    #[test]
+    fn last_bin_includes_end() {
+        let series = histogram(&[0.0, 1.0, 2.0, 3.0], Binning::Count(2));
+        let bins = series.bins();
+
+        assert_eq!(edges(bins), [0.0, 1.5, 3.0]);
+        assert_eq!(bins[0].count, 2);
+        assert_eq!(bins[1].count, 2);
+    }
+
+    #[test]
+    fn constant_samples() {
+        let series = histogram(&[2.0, 2.0, 2.0], Binning::Sturges);
+
+        assert_eq!(edges(series.bins()), [1.5, 2.5]);
+        assert_eq!(series.bins()[0].count, 3);
+    }
+
+    #[test]
+    fn explicit_edges_are_sorted_and_deduplicated() {
+        let series = histogram(
+            &[0.5, 1.5, 1.5, 3.0],
+            Binning::Edges(vec![2.0, 0.0, 1.0, 1.0, 3.0]),
+        )
+        .normalization(Normalization::Density);
+        let bins = series.bins();
+
+        assert_eq!(edges(bins), [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(
+            bins.iter().map(|bin| bin.count).collect::<Vec<_>>(),
+            [1, 2, 1]
+        );
+        assert!(bins.iter().all(|bin| bin.value.is_finite()));
+    }
+
+    #[test]
+    fn normalization() {
+        let samples = [0.0, 0.5, 1.0, 3.0];
+        let values = |normalization| {
+            histogram(&samples, Binning::Edges(vec![0.0, 2.0, 4.0]))
+                .normalization(normalization)
+                .bins()
+                .iter()
+                .map(|bin| bin.value)
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(values(Normalization::Count), [3.0, 1.0]);
+        assert_eq!(values(Normalization::Density), [0.375, 0.125]);
+        assert_eq!(values(Normalization::Cumulative), [0.75, 1.0]);
+    }
+
+    #[test]
+    fn samples_outside_edges_are_ignored() {
+        let series = histogram(&[-1.0, 0.5, 5.0, f32::NAN], Binning::Edges(vec![0.0, 1.0]));
+
+        assert_eq!(series.bins()[0].count, 1);
+    }
+
+    #[test]
+    fn normalization_ignores_samples_outside_edges() {
+        let samples = [-5.0, 0.5, 1.5, 1.5, 9.0];
+        let values = |normalization| {
+            histogram(&samples, Binning::Edges(vec![0.0, 1.0, 2.0]))
+                .normalization(normalization)
+                .bins()
+                .iter()
+                .map(|bin| bin.value)
+                .collect::<Vec<_>>()
+        };
+
+        let density = values(Normalization::Density);
+        assert_eq!(density, [1.0 / 3.0, 2.0 / 3.0]);
+        assert_eq!(density.iter().sum::<f32>(), 1.0);
+        assert_eq!(values(Normalization::Cumulative).last(), Some(&1.0));
+    }
+
+    #[test]
+    fn empty_data_with_explicit_edges() {
+        for normalization in [
+            Normalization::Count,
+            Normalization::Density,
+            Normalization::Cumulative,
+        ] {
+            let series =
+                histogram(&[], Binning::Edges(vec![0.0, 1.0, 2.0])).normalization(normalization);
+
+            assert_eq!(series.bins().len(), 2);
+            assert!(series.bins().iter().all(|bin| bin.value == 0.0));
+            assert_eq!(series.y_range(), 0.0..=0.0);
+        }
+    }
+}
@@ -1,69 +1,126 @@
 use std::ops::RangeInclusive;
 
-use crate::chart::cartesian::Plane;
+use crate::chart::{CoordinateSystem, Target, items};
 
-use super::Series;
+use super::{Series, Table};
 
 use iced::{
-    Color, Point, Vector,
-    widget::canvas::{self, Path, Stroke, path::lyon_path::geom::euclid::Transform2D},
+    Color, Point,
+    widget::canvas::{Path, Stroke},
 };
 
 #[derive(Clone)]
-pub struct LineSeries<Data> {
+pub struct LineSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    pub id: Option<SeriesId>,
     pub data: Data,
-    pub color: Color,
+    pub color: Option<Color>,
 }
 
-impl<Data> LineSeries<Data> {
+impl<SeriesId, Data> LineSeries<SeriesId, Data>
+where
+    SeriesId: Clone,
+{
+    /// Distance in pixels within which the cursor hits the line.
+    const HIT_RADIUS: f32 = 5.0;
+
     pub fn new(data: Data) -> Self {
         Self {
+            id: None,
             data,
-            color: Color::BLACK,
+            color: None,
         }
     }
 
+    /// Makes the segments of the line hoverable, the item id of a segment
+    /// being the index of its first point.
+    pub fn with_id(mut self, id: SeriesId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     pub fn color(mut self, color: impl Into<Color>) -> Self {
-        self.color = color.into();
+        self.color = Some(color.into());
         self
     }
 }
 
-impl<Id, Data> Series<Id> for LineSeries<Data>
+impl<SeriesId, Data> Series<SeriesId> for LineSeries<SeriesId, Data>
 where
+    SeriesId: Clone,
     Data: IntoIterator + Clone,
     Data::Item: Into<(f32, f32)>,
 {
-    fn draw(&self, frame: &mut canvas::Frame, plane: &Plane) {
-        frame.with_save(|frame| {
-            frame.translate(Vector::new(plane.x.margin_min, plane.x.margin_min));
-            frame.scale_nonuniform(Vector::new(plane.x.scale, plane.y.scale));
-            frame.translate(Vector::new(-plane.x.min, plane.y.max));
-
-            let mut iter = self
-                .data
-                .clone()
-                .into_iter()
-                .map(Into::into)
-                .filter(|(x, y)| {
-                    x >= &plane.x.min && x <= &plane.x.max && y >= &plane.y.min && y <= &plane.y.max
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color) {
+        let color = self.color.unwrap_or(color);
+
+        let mut iter = self
+            .data
+            .clone()
+            .into_iter()
+            .map(Into::into)
+            .map(|(x, y)| Point { x, y })
+            .filter(|p| coordinates.is_visible(*p))
+            .map(|p| coordinates.to_screen(p));
+
+        let path = Path::new(|b| {
+            if let Some(p) = iter.next() {
+                b.move_to(p);
+                iter.fold(b, |acc, p| {
+                    acc.line_to(p);
+                    acc
                 });
+            }
+        });
+
+        frame.stroke(&path, Stroke::default().with_width(2.0).with_color(color));
+    }
+
+    fn uses_palette(&self) -> bool {
+        self.color.is_none()
+    }
+
+    fn id(&self) -> Option<SeriesId> {
+        self.id.clone()
+    }
+
+    fn collision_box(&self) -> Option<iced::Rectangle> {
+        Some(iced::Rectangle::with_radius(Self::HIT_RADIUS))
+    }
+
+    fn items(&self) -> Option<(SeriesId, Vec<items::Entry<usize>>)> {
+        let id = self.id.clone()?;
+
+        let points: Vec<_> = self
+            .data
+            .clone()
+            .into_iter()
+            .map(Into::into)
+            .map(|(x, y)| Point { x, y })
+            .collect();
+
+        let items = points
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| items::Entry::segment(index, pair[0], pair[1]))
+            .collect();
+
+        Some((id, items))
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let mut table = Table::new(["x", "y"]);
+
+        self.data
+            .clone()
+            .into_iter()
+            .map(Into::into)
+            .filter(|&(x, y)| coordinates.is_visible(Point { x, y }))
+            .for_each(|(x, y)| table.push_row([x, y]));
 
-            let path = Path::new(|b| {
-                if let Some(p) = iter.next() {
-                    b.move_to(Point { x: p.0, y: p.1 });
-                    iter.fold(b, |acc, p| {
-                        acc.line_to(Point { x: p.0, y: p.1 });
-                        acc
-                    });
-                }
-            });
-
-            frame.stroke(
-                &path.transform(&Transform2D::new(1.0, 0.0, 0.0, -1.0, 0.0, 0.0)),
-                Stroke::default().with_width(2.0).with_color(self.color),
-            );
-        })
+        Some(table)
     }
 
     fn x_range(&self) -> RangeInclusive<f32> {
@@ -5,9 +5,9 @@ use iced::{
     widget::canvas::{self, Path, Stroke},
 };
 
-use crate::chart::{cartesian::Plane, items};
+use crate::chart::{CoordinateSystem, Target, drag, items};
 
-use super::Series;
+use super::{Series, Table};
 
 type StyleFn<'a, Item> = Box<dyn Fn(usize, &Item) -> Style + 'a>;
 
@@ -18,12 +18,15 @@ where
 {
     pub id: Option<SeriesId>,
     pub data: Data,
-    pub color: Color,
+    pub color: Option<Color>,
     x_fn: Option<&'a dyn Fn(&Item) -> f32>,
     y_fn: Option<&'a dyn Fn(&Item) -> f32>,
     collision_box: Option<iced::Rectangle>,
     style: Style,
     pub style_fn: Option<StyleFn<'a, Item>>,
+    selected: Vec<usize>,
+    selection_style_fn: Option<StyleFn<'a, Item>>,
+    drag: Option<drag::Constraints>,
 }
 
 #[derive(Debug, Clone)]
@@ -47,15 +50,18 @@ where
             data,
             x_fn: None,
             y_fn: None,
-            color: Color::BLACK,
+            color: None,
             collision_box: None,
             style: Style::default(),
             style_fn: None,
+            selected: Vec::new(),
+            selection_style_fn: None,
+            drag: None,
         }
     }
 
     pub fn color(mut self, color: impl Into<Color>) -> Self {
-        self.color = color.into();
+        self.color = Some(color.into());
         self
     }
 
@@ -74,11 +80,33 @@ where
         self
     }
 
+    /// Indices of the selected items, e.g. from
+    /// [`crate::chart::Chart::on_select`].
+    pub fn selected(mut self, indices: impl IntoIterator<Item = usize>) -> Self {
+        self.selected = indices.into_iter().collect();
+        // sorted for the lookups while drawing
+        self.selected.sort_unstable();
+        self
+    }
+
+    /// Styles the selected items, overriding [`Self::style_for_each`].
+    pub fn selection_style(mut self, style_fn: impl Fn(usize, &Item) -> Style + 'a) -> Self {
+        self.selection_style_fn = Some(Box::new(style_fn));
+        self
+    }
+
     pub fn with_id(mut self, id: ID) -> Self {
         self.id = Some(id);
         self
     }
 
+    /// Lets the chart drag the items, which requires an id, see
+    /// [`crate::chart::Chart::on_item_drag`].
+    pub fn draggable(mut self, constraints: drag::Constraints) -> Self {
+        self.drag = Some(constraints);
+        self
+    }
+
     pub fn x(mut self, x_fn: &'a dyn Fn(&Item) -> f32) -> Self {
         self.x_fn = Some(x_fn);
         self
@@ -95,25 +123,28 @@ where
     Data: IntoIterator<Item = Item> + Clone,
     Item: Into<(f32, f32)>,
 {
-    fn draw(&self, frame: &mut canvas::Frame, plane: &Plane) {
+    fn draw(&self, frame: &mut dyn Target, coordinates: &dyn CoordinateSystem, color: Color) {
+        let series_color = self.color.unwrap_or(color);
+
         for (index, item) in self.data.clone().into_iter().enumerate() {
-            let style = self
-                .style_fn
+            let style_fn = self
+                .selection_style_fn
                 .as_ref()
-                .map(|func| func(index, &item))
-                .unwrap_or_default();
+                .filter(|_| self.selected.binary_search(&index).is_ok())
+                .or(self.style_fn.as_ref());
+            let style = style_fn.map(|func| func(index, &item)).unwrap_or_default();
 
             let x = self.x_fn.as_ref().map(|f| f(&item));
             let y = self.y_fn.as_ref().map(|f| f(&item));
 
             let p = item.into();
-            let point = Point {
-                x: plane.scale_to_cartesian_x(x.unwrap_or(p.0)),
-                y: plane.scale_to_cartesian_y(y.unwrap_or(p.1)),
-            };
+            let point = coordinates.to_screen(Point {
+                x: x.unwrap_or(p.0),
+                y: y.unwrap_or(p.1),
+            });
 
-            let color = style.color.unwrap_or(self.color);
-            let border_color = style.border_color.unwrap_or(self.color);
+            let color = style.color.unwrap_or(series_color);
+            let border_color = style.border_color.unwrap_or(series_color);
 
             let path = &Path::circle(point, style.radius);
 
@@ -134,6 +165,28 @@ where
         }
     }
 
+    fn uses_palette(&self) -> bool {
+        self.color.is_none()
+    }
+
+    fn table(&self, coordinates: &dyn CoordinateSystem) -> Option<Table> {
+        let mut table = Table::new(["x", "y"]);
+
+        for item in self.data.clone() {
+            let x = self.x_fn.as_ref().map(|f| f(&item));
+            let y = self.y_fn.as_ref().map(|f| f(&item));
+
+            let p = item.into();
+            let point = Point::new(x.unwrap_or(p.0), y.unwrap_or(p.1));
+
+            if coordinates.is_visible(point) {
+                table.push_row([point.x, point.y]);
+            }
+        }
+
+        Some(table)
+    }
+
     fn x_range(&self) -> RangeInclusive<f32> {
         let x_min_cur = f32::INFINITY;
         let x_max_cur = f32::NEG_INFINITY;
@@ -172,6 +225,10 @@ where
         self.id.clone()
     }
 
+    fn draggable(&self) -> Option<&drag::Constraints> {
+        self.drag.as_ref()
+    }
+
     fn collision_box(&self) -> Option<iced::Rectangle> {
         let style = Style::default();
         self.collision_box
@@ -0,0 +1,70 @@
+//! Small statistics helpers shared by the series which aggregate raw samples.
+
+/// Collects the finite samples in ascending order.
+pub fn sorted(samples: impl IntoIterator<Item = f32>) -> Vec<f32> {
+    let mut samples: Vec<f32> = samples.into_iter().filter(|s| s.is_finite()).collect();
+    samples.sort_by(f32::total_cmp);
+
+    samples
+}
+
+/// Linearly interpolated quantile of already sorted samples, `q` in `0.0..=1.0`.
+pub fn quantile(sorted: &[f32], q: f32) -> f32 {
+    match sorted.len() {
+        0 => f32::NAN,
+        1 => sorted[0],
+        len => {
+            let pos = q.clamp(0.0, 1.0) * (len - 1) as f32;
+            let lower = pos.floor() as usize;
+            let upper = pos.ceil() as usize;
+            let fract = pos - lower as f32;
+
+            sorted[lower] + (sorted[upper] - sorted[lower]) * fract
+        }
+    }
+}
+
+pub fn standard_deviation(samples: &[f32]) -> f32 {
+    let len = samples.len() as f32;
+    if len < 2.0 {
+        return 0.0;
+    }
+
+    let mean = samples.iter().sum::<f32>() / len;
+    let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / (len - 1.0);
+
+    variance.sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorted_drops_non_finite() {
+        assert_eq!(
+            sorted([3.0, f32::NAN, 1.0, f32::INFINITY, 2.0]),
+            [1.0, 2.0, 3.0]
+        );
+    }
+
+    #[test]
+    fn quantiles() {
+        let samples = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(quantile(&samples, 0.0), 1.0);
+        assert_eq!(quantile(&samples, 0.25), 1.75);
+        assert_eq!(quantile(&samples, 0.5), 2.5);
+        assert_eq!(quantile(&samples, 1.0), 4.0);
+        assert_eq!(quantile(&[5.0], 0.3), 5.0);
+        assert!(quantile(&[], 0.5).is_nan());
+    }
+
+    #[test]
+    fn sample_standard_deviation() {
+        let samples = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        assert!((standard_deviation(&samples) - (32.0f32 / 7.0).sqrt()).abs() < 1e-6);
+        assert_eq!(standard_deviation(&[1.0]), 0.0);
+    }
+}
@@ -0,0 +1,122 @@
+use iced::{Background, Border, Color, Theme};
+
+/// The appearance of a [`Chart`](super::Chart).
+///
+/// Colors set directly on an axis, its ticks or labels take precedence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    /// Background of the whole chart, margins included.
+    pub background: Option<Background>,
+    /// Border around the whole chart, its radius rounds the background.
+    pub border: Border,
+    /// Background of the plot area, i.e. the chart without its margins.
+    pub plot_background: Option<Background>,
+    /// Border around the plot area.
+    pub plot_border: Border,
+    pub axis: Color,
+    pub tick: Color,
+    pub label: Color,
+    /// Color of grid lines, e.g. the inner rings and spokes of polar charts.
+    pub grid: Color,
+    /// Colors handed out to series without an explicit color.
+    pub palette: Vec<Color>,
+}
+
+/// Colors handed out to series without an explicit color, in order.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum Palette {
+    /// The palette of the chart [`Style`].
+    #[default]
+    Theme,
+    Tableau10,
+    /// A color blind safe palette by Masataka Okabe and Kei Ito.
+    OkabeIto,
+    Custom(Vec<Color>),
+}
+
+impl Palette {
+    pub fn colors<'a>(&'a self, style: &'a Style) -> &'a [Color] {
+        match self {
+            Palette::Theme => &style.palette,
+            Palette::Tableau10 => &TABLEAU10,
+            Palette::OkabeIto => &OKABE_ITO,
+            Palette::Custom(colors) => colors,
+        }
+    }
+}
+
+pub const TABLEAU10: [Color; 10] = [
+    Color::from_rgb8(0x1f, 0x77, 0xb4),
+    Color::from_rgb8(0xff, 0x7f, 0x0e),
+    Color::from_rgb8(0x2c, 0xa0, 0x2c),
+    Color::from_rgb8(0xd6, 0x27, 0x28),
+    Color::from_rgb8(0x94, 0x67, 0xbd),
+    Color::from_rgb8(0x8c, 0x56, 0x4b),
+    Color::from_rgb8(0xe3, 0x77, 0xc2),
+    Color::from_rgb8(0x7f, 0x7f, 0x7f),
+    Color::from_rgb8(0xbc, 0xbd, 0x22),
+    Color::from_rgb8(0x17, 0xbe, 0xcf),
+];
+
+pub const OKABE_ITO: [Color; 8] = [
+    Color::from_rgb8(0xe6, 0x9f, 0x00),
+    Color::from_rgb8(0x56, 0xb4, 0xe9),
+    Color::from_rgb8(0x00, 0x9e, 0x73),
+    Color::from_rgb8(0xf0, 0xe4, 0x42),
+    Color::from_rgb8(0x00, 0x72, 0xb2),
+    Color::from_rgb8(0xd5, 0x5e, 0x00),
+    Color::from_rgb8(0xcc, 0x79, 0xa7),
+    Color::from_rgb8(0x00, 0x00, 0x00),
+];
+
+/// The theme catalog of a [`Chart`](super::Chart).
+pub trait Catalog {
+    /// The item class of the [`Catalog`].
+    type Class<'a>;
+
+    /// The default class produced by the [`Catalog`].
+    fn default<'a>() -> Self::Class<'a>;
+
+    /// The [`Style`] of a class.
+    fn style(&self, class: &Self::Class<'_>) -> Style;
+}
+
+/// A styling function for a [`Chart`](super::Chart).
+pub type StyleFn<'a, Theme> = Box<dyn Fn(&Theme) -> Style + 'a>;
+
+impl Catalog for Theme {
+    type Class<'a> = StyleFn<'a, Self>;
+
+    fn default<'a>() -> Self::Class<'a> {
+        Box::new(default)
+    }
+
+    fn style(&self, class: &Self::Class<'_>) -> Style {
+        class(self)
+    }
+}
+
+/// The default style of a [`Chart`](super::Chart), derived from the palette of
+/// the theme.
+pub fn default(theme: &Theme) -> Style {
+    let palette = theme.extended_palette();
+
+    Style {
+        background: None,
+        border: Border::default(),
+        plot_background: None,
+        plot_border: Border::default(),
+        axis: palette.background.strong.color,
+        tick: palette.background.strong.color,
+        label: palette.background.base.text,
+        grid: palette.background.weak.color,
+        palette: vec![
+            palette.primary.base.color,
+            palette.success.base.color,
+            palette.danger.base.color,
+            palette.primary.strong.color,
+            palette.success.strong.color,
+            palette.danger.strong.color,
+        ],
+    }
+}
@@ -0,0 +1,189 @@
+use std::fmt::Write;
+
+use iced::font::{Family, Style as FontStyle, Weight};
+use iced::widget::canvas::path::lyon_path::Event;
+use iced::widget::canvas::{self, Fill, LineCap, LineJoin, Path, Stroke, fill};
+use iced::{Size, alignment};
+
+use super::Target;
+use super::target::solid_color;
+
+/// An SVG document, which a chart can be drawn onto like onto a frame.
+///
+/// See [`crate::chart::Chart::to_svg`]. Gradients are exported as their first
+/// color.
+#[derive(Debug, Clone)]
+pub struct Document {
+    size: Size,
+    body: String,
+}
+
+impl Document {
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            body: String::new(),
+        }
+    }
+
+    /// The finished document.
+    pub fn finish(self) -> String {
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{w}\" height=\"{h}\" viewBox=\"0 0 {w} {h}\">\n{body}</svg>\n",
+            w = self.size.width,
+            h = self.size.height,
+            body = self.body
+        )
+    }
+}
+
+impl Target for Document {
+    fn size(&self) -> Size {
+        self.size
+    }
+
+    fn fill(&mut self, path: &Path, fill: Fill) {
+        let rule = match fill.rule {
+            fill::Rule::NonZero => "nonzero",
+            fill::Rule::EvenOdd => "evenodd",
+        };
+
+        let _ = writeln!(
+            self.body,
+            "<path d=\"{}\" {} fill-rule=\"{rule}\"/>",
+            path_data(path),
+            paint("fill", fill.style),
+        );
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>) {
+        if stroke.width <= 0.0 {
+            return;
+        }
+
+        let cap = match stroke.line_cap {
+            LineCap::Butt => "butt",
+            LineCap::Square => "square",
+            LineCap::Round => "round",
+        };
+        let join = match stroke.line_join {
+            LineJoin::Miter => "miter",
+            LineJoin::Round => "round",
+            LineJoin::Bevel => "bevel",
+        };
+
+        let mut dash = String::new();
+        if !stroke.line_dash.segments.is_empty() {
+            let segments: Vec<_> = stroke
+                .line_dash
+                .segments
+                .iter()
+                .map(f32::to_string)
+                .collect();
+
+            dash = format!(
+                " stroke-dasharray=\"{}\" stroke-dashoffset=\"{}\"",
+                segments.join(" "),
+                stroke.line_dash.offset
+            );
+        }
+
+        let _ = writeln!(
+            self.body,
+            "<path d=\"{}\" fill=\"none\" {} stroke-width=\"{}\" stroke-linecap=\"{cap}\" stroke-linejoin=\"{join}\"{dash}/>",
+            path_data(path),
+            paint("stroke", stroke.style),
+            stroke.width,
+        );
+    }
+
+    fn fill_text(&mut self, text: canvas::Text) {
+        let anchor = match alignment::Horizontal::from(text.align_x) {
+            alignment::Horizontal::Left => "start",
+            alignment::Horizontal::Center => "middle",
+            alignment::Horizontal::Right => "end",
+        };
+        let baseline = match text.align_y {
+            alignment::Vertical::Top => "text-before-edge",
+            alignment::Vertical::Center => "central",
+            alignment::Vertical::Bottom => "text-after-edge",
+        };
+
+        let family = match text.font.family {
+            Family::Name(name) => escape(name),
+            Family::Serif => "serif".to_owned(),
+            Family::SansSerif => "sans-serif".to_owned(),
+            Family::Cursive => "cursive".to_owned(),
+            Family::Fantasy => "fantasy".to_owned(),
+            Family::Monospace => "monospace".to_owned(),
+        };
+        let style = match text.font.style {
+            FontStyle::Normal => "normal",
+            FontStyle::Italic => "italic",
+            FontStyle::Oblique => "oblique",
+        };
+        let weight = match text.font.weight {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        };
+
+        let _ = writeln!(
+            self.body,
+            "<text x=\"{}\" y=\"{}\" {} font-size=\"{}\" font-family=\"{family}\" font-style=\"{style}\" font-weight=\"{weight}\" text-anchor=\"{anchor}\" dominant-baseline=\"{baseline}\">{}</text>",
+            text.position.x,
+            text.position.y,
+            paint("fill", canvas::Style::Solid(text.color)),
+            text.size.0,
+            escape(&text.content),
+        );
+    }
+}
+
+fn path_data(path: &Path) -> String {
+    let mut data = String::new();
+
+    for event in path.raw().iter() {
+        let _ = match event {
+            Event::Begin { at } => write!(data, "M{} {} ", at.x, at.y),
+            Event::Line { to, .. } => write!(data, "L{} {} ", to.x, to.y),
+            Event::Quadratic { ctrl, to, .. } => {
+                write!(data, "Q{} {} {} {} ", ctrl.x, ctrl.y, to.x, to.y)
+            }
+            Event::Cubic {
+                ctrl1, ctrl2, to, ..
+            } => write!(
+                data,
+                "C{} {} {} {} {} {} ",
+                ctrl1.x, ctrl1.y, ctrl2.x, ctrl2.y, to.x, to.y
+            ),
+            Event::End { close: true, .. } => write!(data, "Z "),
+            Event::End { close: false, .. } => Ok(()),
+        };
+    }
+
+    data.trim_end().to_owned()
+}
+
+/// Color and opacity attributes for `fill` or `stroke`.
+fn paint(attribute: &str, style: canvas::Style) -> String {
+    let [r, g, b, a] = solid_color(style).into_rgba8();
+
+    format!(
+        "{attribute}=\"#{r:02x}{g:02x}{b:02x}\" {attribute}-opacity=\"{}\"",
+        f32::from(a) / 255.0
+    )
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
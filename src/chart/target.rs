@@ -0,0 +1,57 @@
+use iced::widget::canvas::{self, Fill, Path, Stroke};
+use iced::{Color, Point, Size};
+
+/// Something a chart can be drawn onto.
+///
+/// Axes, annotations and series only use these few operations, so the same
+/// drawing code serves the [`canvas::Frame`] of the widget and exports like
+/// [`super::svg::Document`].
+pub trait Target {
+    fn size(&self) -> Size;
+
+    fn fill(&mut self, path: &Path, fill: Fill);
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: Fill) {
+        self.fill(&Path::rectangle(top_left, size), fill);
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>);
+
+    fn fill_text(&mut self, text: canvas::Text);
+}
+
+impl Target for canvas::Frame {
+    fn size(&self) -> Size {
+        canvas::Frame::size(self)
+    }
+
+    fn fill(&mut self, path: &Path, fill: Fill) {
+        canvas::Frame::fill(self, path, fill);
+    }
+
+    fn fill_rectangle(&mut self, top_left: Point, size: Size, fill: Fill) {
+        canvas::Frame::fill_rectangle(self, top_left, size, fill);
+    }
+
+    fn stroke(&mut self, path: &Path, stroke: Stroke<'_>) {
+        canvas::Frame::stroke(self, path, stroke);
+    }
+
+    fn fill_text(&mut self, text: canvas::Text) {
+        canvas::Frame::fill_text(self, text);
+    }
+}
+
+/// The color of a fill or stroke style, gradients are reduced to their first
+/// stop.
+pub(super) fn solid_color(style: canvas::Style) -> Color {
+    match style {
+        canvas::Style::Solid(color) => color,
+        canvas::Style::Gradient(canvas::Gradient::Linear(linear)) => linear
+            .stops
+            .iter()
+            .flatten()
+            .next()
+            .map_or(Color::TRANSPARENT, |stop| stop.color),
+    }
+}
@@ -0,0 +1,103 @@
+//! Golden image tests of the CPU renderer.
+//!
+//! Labels are hidden, as text depends on the fonts of the system. Run with
+//! `PRISM_BLESS=1` to write the current output as the new golden images.
+
+use std::path::PathBuf;
+
+use iced::{Color, Size, Theme};
+use prism::chart::series::{line_series, point_series};
+use prism::chart::{Chart, Labels, Style, style};
+
+const SIZE: Size = Size::new(160.0, 120.0);
+
+/// Allowed difference per channel, to absorb rounding of the PNG round trip.
+const TOLERANCE: u8 = 2;
+
+fn chart<'a>() -> Chart<'a, (), usize> {
+    Chart::new()
+        .x_range(-1.0..=5.0)
+        .y_range(-1.0..=5.0)
+        .x_labels(Labels::default().hidden(true))
+        .y_labels(Labels::default().hidden(true))
+        .style(|theme: &Theme| Style {
+            background: Some(Color::WHITE.into()),
+            ..style::default(theme)
+        })
+}
+
+fn data() -> Vec<(f32, f32)> {
+    vec![(0.0, 0.0), (1.0, 2.0), (2.0, 1.5), (3.0, 4.0), (4.0, 3.0)]
+}
+
+fn assert_golden(name: &str, chart: &Chart<'_, (), usize>, scale_factor: f32) {
+    let image = chart.to_image(SIZE, scale_factor, &Theme::Light).unwrap();
+
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"));
+
+    if std::env::var_os("PRISM_BLESS").is_some() {
+        let mut pixmap = tiny_skia::Pixmap::new(image.width(), image.height()).unwrap();
+        for (pixel, rgba) in pixmap
+            .pixels_mut()
+            .iter_mut()
+            .zip(image.rgba().chunks_exact(4))
+        {
+            *pixel =
+                tiny_skia::ColorU8::from_rgba(rgba[0], rgba[1], rgba[2], rgba[3]).premultiply();
+        }
+
+        pixmap.save_png(&path).unwrap();
+        return;
+    }
+
+    let golden = tiny_skia::Pixmap::load_png(&path)
+        .unwrap_or_else(|err| panic!("golden image {} is missing: {err}", path.display()));
+
+    assert_eq!(
+        (image.width(), image.height()),
+        (golden.width(), golden.height()),
+        "size of {name} differs"
+    );
+
+    let expected = golden.pixels().iter().flat_map(|pixel| {
+        let color = pixel.demultiply();
+        [color.red(), color.green(), color.blue(), color.alpha()]
+    });
+
+    let differences = image
+        .rgba()
+        .into_iter()
+        .zip(expected)
+        .filter(|(actual, expected)| actual.abs_diff(*expected) > TOLERANCE)
+        .count();
+
+    assert_eq!(differences, 0, "{name} differs from its golden image");
+}
+
+#[test]
+fn axes() {
+    assert_golden("axes", &chart(), 1.0);
+}
+
+#[test]
+fn line() {
+    let chart = chart().push_series(line_series(data()).color(Color::from_rgb8(0x1f, 0x77, 0xb4)));
+
+    assert_golden("line", &chart, 1.0);
+}
+
+#[test]
+fn points() {
+    let chart = chart().push_series(point_series(data()).color(Color::from_rgb8(0xd6, 0x27, 0x28)));
+
+    assert_golden("points", &chart, 1.0);
+}
+
+#[test]
+fn scale_factor() {
+    let chart = chart().push_series(line_series(data()).color(Color::from_rgb8(0x1f, 0x77, 0xb4)));
+
+    assert_golden("line_2x", &chart, 2.0);
+}